@@ -45,7 +45,7 @@ def generate_kennitalas(
 
 def rust_from_kennitalas(kennitalas: List[str]) -> str:
     num = len(kennitalas)
-    test = f"const KENNITALAS: [&'static str; {num}] = [\n"
+    test = f"const KENNITALAS: [&str; {num}] = [\n"
     for kt in kennitalas:
         test += f'    "{kt}",\n'
     test += "];\n"
@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// 1 000 000 kennitölur, where every 100th is invalid.
+fn items() -> Vec<&'static str> {
+    (0..1_000_000)
+        .map(|i| {
+            if i % 100 == 0 {
+                "3110002930"
+            } else {
+                "3110002920"
+            }
+        })
+        .collect()
+}
+
+fn batch(c: &mut Criterion) {
+    let items = items();
+    let mut group = c.benchmark_group("batch");
+    group.sample_size(20);
+    group.bench_function("validate_all", |b| {
+        b.iter(|| kennitolur::validate_all(black_box(&items)))
+    });
+    group.bench_function("validate_all_par", |b| {
+        b.iter(|| kennitolur::validate_all_par(black_box(&items)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, batch);
+criterion_main!(benches);
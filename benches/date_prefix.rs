@@ -0,0 +1,51 @@
+use chrono::NaiveDate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use kennitolur::{DatePrefix, Kennitala};
+
+/// Every kennitala of individuals born in 1990, in sorted order, as found in a
+/// sorted registry extract.
+fn sorted_kennitolur() -> Vec<String> {
+    let mut kennitolur: Vec<String> = NaiveDate::from_ymd_opt(1990, 1, 1)
+        .unwrap()
+        .iter_days()
+        .take(365)
+        .flat_map(Kennitala::all_for_date)
+        .map(|kt| kt.to_string())
+        .collect();
+    kennitolur.sort();
+    kennitolur
+}
+
+fn date_prefix(c: &mut Criterion) {
+    let kennitolur = sorted_kennitolur();
+    let mut group = c.benchmark_group("date_prefix");
+    group.bench_function("new", |b| {
+        b.iter(|| {
+            for kt in &kennitolur {
+                black_box(Kennitala::new(black_box(kt)).unwrap());
+            }
+        })
+    });
+    group.bench_function("new_with_cached_date", |b| {
+        b.iter(|| {
+            let mut cached: Option<(&str, DatePrefix)> = None;
+            for kt in &kennitolur {
+                let kt = black_box(kt.as_str());
+                let (prefix, tail) = kt.split_at(6);
+                let date = match cached {
+                    Some((cached_prefix, date)) if cached_prefix == prefix => date,
+                    _ => {
+                        let date = DatePrefix::parse(prefix).unwrap();
+                        cached = Some((prefix, date));
+                        date
+                    }
+                };
+                black_box(Kennitala::new_with_cached_date(&date, tail).unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, date_prefix);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+/// An export of 100 000 kennitölur, one per line, where every 100th is
+/// invalid.
+fn export() -> Vec<u8> {
+    let mut export = Vec::new();
+    for i in 0..100_000 {
+        if i % 100 == 0 {
+            export.extend_from_slice(b"3110002930\n");
+        } else {
+            export.extend_from_slice(b"3110002920\n");
+        }
+    }
+    export
+}
+
+fn lines(c: &mut Criterion) {
+    let export = export();
+    let mut group = c.benchmark_group("lines");
+    group.throughput(Throughput::Bytes(export.len() as u64));
+    group.bench_function("validate_lines", |b| {
+        b.iter(|| kennitolur::validate_lines(black_box(&export[..])).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lines);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use kennitolur::Kennitala;
+
+/// 100 000 packed records of 10 bytes, where every 100th is invalid.
+fn data() -> Vec<u8> {
+    let mut data = Vec::new();
+    for i in 0..100_000 {
+        if i % 100 == 0 {
+            data.extend_from_slice(b"3110002930");
+        } else {
+            data.extend_from_slice(b"3110002920");
+        }
+    }
+    data
+}
+
+fn packed(c: &mut Criterion) {
+    let data = data();
+    let mut group = c.benchmark_group("packed");
+    group.throughput(Throughput::Bytes(data.len() as u64));
+    group.bench_function("validate_packed", |b| {
+        b.iter(|| kennitolur::validate_packed(black_box(&data), 10, 0))
+    });
+    group.bench_function("new", |b| {
+        b.iter(|| {
+            black_box(&data)
+                .chunks_exact(10)
+                .enumerate()
+                .filter(|(_, record)| Kennitala::new(std::str::from_utf8(record).unwrap()).is_err())
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, packed);
+criterion_main!(benches);
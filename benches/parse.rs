@@ -0,0 +1,21 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use kennitolur::Kennitala;
+
+const KENNITALA: &[u8] = b"3110002920";
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("new", |b| {
+        b.iter(|| Kennitala::new(std::str::from_utf8(black_box(KENNITALA)).unwrap()))
+    });
+    group.bench_function("from_bytes", |b| {
+        b.iter(|| Kennitala::from_bytes(black_box(KENNITALA)))
+    });
+    group.bench_function("validate", |b| {
+        b.iter(|| kennitolur::validate(std::str::from_utf8(black_box(KENNITALA)).unwrap()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+#![no_main]
+use std::convert::TryFrom;
+
+use kennitolur::Kennitala;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|n: u64| {
+    // Any integer which is accepted must be the decimal form of the kennitala
+    // it was read as, whether or not its leading zero was dropped.
+    let kt = Kennitala::from_u64(n);
+    if let Ok(kt) = kt {
+        assert_eq!(kt.to_decimal_u64(), n);
+        assert_eq!(Kennitala::new(&format!("{:010}", n)), Ok(kt));
+    }
+    if let Ok(n) = u32::try_from(n) {
+        assert_eq!(Kennitala::from_u32(n), kt);
+    }
+});
@@ -4,7 +4,8 @@ use libfuzzer_sys::fuzz_target;
 
 fuzz_target!(|data: &[u8]| {
     if let Ok(string) = std::str::from_utf8(data) {
-        match Kennitala::new(string) {
+        let strict = Kennitala::new(string);
+        match strict {
             Ok(kt) => {
                 kt.get_day();
                 kt.get_month();
@@ -16,5 +17,19 @@ fuzz_target!(|data: &[u8]| {
             }
             Err(_) => {}
         }
+
+        // The lenient parser must agree with the strict parser once the
+        // optional hyphen has been removed.
+        match Kennitala::new_lenient(string) {
+            Ok(kt) => {
+                let without_hyphen = if string.len() == 11 {
+                    format!("{}{}", &string[..6], &string[7..])
+                } else {
+                    string.to_owned()
+                };
+                assert_eq!(Kennitala::new(&without_hyphen).ok(), Some(kt));
+            }
+            Err(_) => assert!(strict.is_err()),
+        }
     }
 });
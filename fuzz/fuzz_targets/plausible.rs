@@ -0,0 +1,21 @@
+#![no_main]
+use kennitolur::arbitrary::PlausibleKennitala;
+use kennitolur::Kennitala;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: PlausibleKennitala| {
+    let string = input.as_str();
+    match Kennitala::new(string) {
+        Ok(kt) => {
+            assert_eq!(kt.to_string(), string);
+            assert_eq!(Kennitala::from_packed(kt.as_u32()), Some(kt));
+            assert_eq!(Kennitala::from_u64(kt.to_decimal_u64()), Ok(kt));
+            assert_eq!(Kennitala::new_lenient(&kt.to_string_pretty()), Ok(kt));
+            assert!(kennitolur::is_valid(string));
+        }
+        Err(e) => {
+            assert_eq!(kennitolur::validate(string), Err(e));
+            assert!(!kennitolur::is_valid(string));
+        }
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+use kennitolur::Kennitala;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|raw: u32| {
+    // Any value accepted as a packed representation must be the
+    // representation of a kennitala which parses from its own digits.
+    if let Ok(kt) = Kennitala::from_raw(raw) {
+        assert_eq!(kt.to_raw(), raw);
+        let string = kt.to_string();
+        let reparsed = if kt.has_valid_checksum() {
+            Kennitala::new(&string)
+        } else {
+            Kennitala::new_unchecked_checksum(&string)
+        };
+        assert_eq!(reparsed, Ok(kt));
+    }
+    assert_eq!(
+        Kennitala::from_be_bytes(raw.to_be_bytes()),
+        Kennitala::from_raw(raw)
+    );
+});
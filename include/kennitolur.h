@@ -0,0 +1,80 @@
+#ifndef KENNITOLUR_H
+#define KENNITOLUR_H
+
+/* Generated from src/ffi.rs by cbindgen. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Success.
+#define KENNITOLUR_OK 0
+
+// `KennitalaError::InvalidLength`.
+#define KENNITOLUR_INVALID_LENGTH -1
+
+// `KennitalaError::InvalidNumber`.
+#define KENNITOLUR_INVALID_NUMBER -2
+
+// `KennitalaError::InvalidDay`.
+#define KENNITOLUR_INVALID_DAY -3
+
+// `KennitalaError::InvalidMonth`.
+#define KENNITOLUR_INVALID_MONTH -4
+
+// `KennitalaError::InvalidRandomDigits`.
+#define KENNITOLUR_INVALID_RANDOM_DIGITS -5
+
+// `KennitalaError::InvalidChecksum`.
+#define KENNITOLUR_INVALID_CHECKSUM -6
+
+// `KennitalaError::InvalidCentury`.
+#define KENNITOLUR_INVALID_CENTURY -7
+
+// `KennitalaError::DateOutOfRange`.
+#define KENNITOLUR_DATE_OUT_OF_RANGE -8
+
+// `KennitalaError::Placeholder`.
+#define KENNITOLUR_PLACEHOLDER -9
+
+// `KennitalaError::InvalidRepresentation`.
+#define KENNITOLUR_INVALID_REPRESENTATION -10
+
+// A pointer argument was null. This is far from the codes of the
+// `KennitalaError` variants, so that new variants don't collide with it.
+#define KENNITOLUR_NULL_POINTER -100
+
+
+
+// Check whether the `len` bytes at `kennitala` are a valid kennitala, as with
+// `Kennitala::from_bytes`. The string need not be null-terminated. Returns
+// `KENNITOLUR_OK` if it is valid, and the code of the error otherwise.
+//
+// # Safety
+//
+// `kennitala` must be null, or point to at least `len` readable bytes.
+int32_t kennitolur_validate(const char *kennitala, size_t len);
+
+// Parse the `len` bytes at `kennitala`, as with `kennitolur_validate`, and
+// on success write its packed representation, as with `Kennitala::as_u32`,
+// to `out`. Nothing is written on failure.
+//
+// # Safety
+//
+// `kennitala` must be null, or point to at least `len` readable bytes, and
+// `out` must be null or valid for writing a `uint32_t`.
+int32_t kennitolur_parse(const char *kennitala, size_t len, uint32_t *out);
+
+// Write the 10 digits of the kennitala with the given packed representation,
+// as returned by `kennitolur_parse`, followed by a null terminator, to `out`.
+// The packed representation is validated as with `validate_raw`, and nothing
+// is written if it is invalid.
+//
+// # Safety
+//
+// `out` must be null or valid for writing 11 bytes.
+int32_t kennitolur_format(uint32_t packed, char *out);
+
+#endif  /* KENNITOLUR_H */
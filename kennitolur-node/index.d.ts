@@ -0,0 +1,39 @@
+/* auto-generated by NAPI-RS */
+/* eslint-disable */
+/** The fields of a valid kennitala. */
+export interface KennitalaInfo {
+  /** The canonical 10 digits. */
+  kennitala: string
+  /** The 10 digits with a hyphen after the sixth. */
+  pretty: string
+  /** The day of birth, or of registration for companies, from 1 to 31. */
+  day: number
+  /** The month of birth, or of registration for companies, from 1 to 12. */
+  month: number
+  /** The year of birth, or of registration for companies. */
+  year: number
+  /** The two random digits, from 20 to 99. */
+  randomDigits: number
+  /** The checksum digit. */
+  checksumDigit: number
+  /** Whether the kennitala belongs to a company. */
+  isCompany: boolean
+}
+
+/**
+ * Format the given kennitala, which may have a hyphen after the sixth digit,
+ * with or without the hyphen. Throws if it is invalid.
+ */
+export declare function format(value: string, hyphen: boolean): string
+
+/** Parse the given kennitala, throwing if it is invalid. */
+export declare function parse(input: string): KennitalaInfo
+
+/** Check whether the given string is a valid kennitala. */
+export declare function validate(input: string): boolean
+
+/**
+ * Check whether each of the given strings is a valid kennitala, off the
+ * event loop.
+ */
+export declare function validateMany(inputs: Array<string>): Promise<Array<boolean>>
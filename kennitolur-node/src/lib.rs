@@ -0,0 +1,114 @@
+//! Node.js bindings for `kennitolur`, built with napi-rs:
+//!
+//! ```sh
+//! npm install
+//! npm run build
+//! npm test
+//! ```
+//!
+//! ```js
+//! const { validate, parse, format, validateMany } = require("kennitolur");
+//!
+//! validate("3110002920"); // true
+//! parse("3110002920").year; // 2000
+//! format("3110002920", true); // "311000-2920"
+//! await validateMany(["3110002920", "3110002930"]); // [true, false]
+//! ```
+//!
+//! Invalid kennitölur throw an `Error` whose `code` is the
+//! `KennitalaError::variant_name` of the error, such as `"InvalidChecksum"`,
+//! as in the `actix-web` and `wasm` integrations, and whose message is that
+//! of the error.
+use kennitolur::{Kennitala, KennitalaError};
+use napi::bindgen_prelude::AsyncTask;
+use napi::{Env, Error, Task};
+use napi_derive::napi;
+
+/// The fields of a valid kennitala.
+#[napi(object)]
+pub struct KennitalaInfo {
+    /// The canonical 10 digits.
+    pub kennitala: String,
+    /// The 10 digits with a hyphen after the sixth.
+    pub pretty: String,
+    /// The day of birth, or of registration for companies, from 1 to 31.
+    pub day: u32,
+    /// The month of birth, or of registration for companies, from 1 to 12.
+    pub month: u32,
+    /// The year of birth, or of registration for companies.
+    pub year: u32,
+    /// The two random digits, from 20 to 99.
+    pub random_digits: u32,
+    /// The checksum digit.
+    pub checksum_digit: u32,
+    /// Whether the kennitala belongs to a company.
+    pub is_company: bool,
+}
+
+impl From<Kennitala> for KennitalaInfo {
+    fn from(kennitala: Kennitala) -> Self {
+        KennitalaInfo {
+            kennitala: kennitala.to_string(),
+            pretty: kennitala.to_string_pretty(),
+            day: kennitala.get_day(),
+            month: kennitala.get_month(),
+            year: kennitala.get_year(),
+            random_digits: u32::from(kennitala.get_random_pair()),
+            checksum_digit: u32::from(kennitala.get_checksum_digit()),
+            is_company: kennitala.is_company(),
+        }
+    }
+}
+
+fn to_napi_error(error: KennitalaError) -> Error<&'static str> {
+    Error::new(error.variant_name(), error)
+}
+
+/// Check whether the given string is a valid kennitala.
+#[napi]
+pub fn validate(input: String) -> bool {
+    kennitolur::is_valid(&input)
+}
+
+/// Parse the given kennitala, throwing if it is invalid.
+#[napi]
+pub fn parse(input: String) -> Result<KennitalaInfo, Error<&'static str>> {
+    Kennitala::new(&input)
+        .map(KennitalaInfo::from)
+        .map_err(to_napi_error)
+}
+
+/// Format the given kennitala, which may have a hyphen after the sixth digit,
+/// with or without the hyphen. Throws if it is invalid.
+#[napi]
+pub fn format(value: String, hyphen: bool) -> Result<String, Error<&'static str>> {
+    let kennitala = Kennitala::new_lenient(&value).map_err(to_napi_error)?;
+    if hyphen {
+        Ok(kennitala.to_string_pretty())
+    } else {
+        Ok(kennitala.to_string())
+    }
+}
+
+/// Validates a batch of kennitölur on the libuv thread pool.
+pub struct ValidateMany(Vec<String>);
+
+impl Task for ValidateMany {
+    type Output = Vec<bool>;
+    type JsValue = Vec<bool>;
+
+    fn compute(&mut self) -> napi::Result<Vec<bool>> {
+        Ok(self.0.iter().map(|s| kennitolur::is_valid(s)).collect())
+    }
+
+    fn resolve(&mut self, _env: Env, output: Vec<bool>) -> napi::Result<Vec<bool>> {
+        Ok(output)
+    }
+}
+
+/// Check whether each of the given strings is a valid kennitala, off the
+/// event loop.
+#[napi(ts_return_type = "Promise<Array<boolean>>")]
+pub fn validate_many(inputs: Vec<String>) -> AsyncTask<ValidateMany> {
+    AsyncTask::new(ValidateMany(inputs))
+}
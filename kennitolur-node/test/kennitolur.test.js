@@ -0,0 +1,75 @@
+const assert = require("node:assert/strict");
+const { test } = require("node:test");
+
+const { validate, parse, format, validateMany } = require("..");
+
+test("validate", () => {
+  assert.equal(validate("3110002920"), true);
+  assert.equal(validate("7112993009"), true);
+  assert.equal(validate("3110002930"), false);
+  assert.equal(validate("311000-2920"), false);
+  assert.equal(validate(""), false);
+});
+
+test("parse", () => {
+  assert.deepEqual(parse("3110002920"), {
+    kennitala: "3110002920",
+    pretty: "311000-2920",
+    day: 31,
+    month: 10,
+    year: 2000,
+    randomDigits: 29,
+    checksumDigit: 2,
+    isCompany: false,
+  });
+  const company = parse("7112993009");
+  assert.equal(company.day, 31);
+  assert.equal(company.year, 1999);
+  assert.equal(company.isCompany, true);
+});
+
+test("parse errors", () => {
+  const cases = [
+    ["3110002930", "InvalidChecksum", "The kennitala's checksum is invalid"],
+    ["311000", "InvalidLength", "Length 6 is invalid"],
+    ["31100O2920", "InvalidNumber", "Invalid character 'O' at position 5"],
+    ["3110002921", "InvalidCentury", "Century of birth is invalid"],
+    ["0000000000", "Placeholder", "The kennitala is a placeholder"],
+  ];
+  for (const [input, code, message] of cases) {
+    assert.throws(() => parse(input), (error) => {
+      assert.ok(error instanceof Error);
+      assert.equal(error.code, code);
+      assert.equal(error.message, message);
+      return true;
+    });
+  }
+  // Arguments which are not strings are rejected before validation.
+  assert.throws(() => parse(3110002920), { code: "StringExpected" });
+});
+
+test("format", () => {
+  assert.equal(format("3110002920", true), "311000-2920");
+  assert.equal(format("3110002920", false), "3110002920");
+  assert.equal(format("311000-2920", false), "3110002920");
+  assert.equal(format("311000-2920", true), "311000-2920");
+  assert.throws(() => format("311000-2930", true), {
+    code: "InvalidChecksum",
+    message: "The kennitala's checksum is invalid",
+  });
+  assert.throws(() => format("31100-02920", false), {
+    code: "InvalidNumber",
+    message: "Invalid character '-' at position 5",
+  });
+});
+
+test("validateMany", async () => {
+  assert.deepEqual(await validateMany([]), []);
+  const inputs = ["3110002920", "3110002930", "", "1703715939"];
+  const pending = validateMany(inputs);
+  assert.ok(pending instanceof Promise);
+  assert.deepEqual(await pending, [true, false, false, true]);
+  assert.throws(() => validateMany(["3110002920", 42]), {
+    code: "StringExpected",
+  });
+});
@@ -0,0 +1,97 @@
+//! Python bindings for `kennitolur`, built with `maturin`:
+//!
+//! ```sh
+//! pip install maturin pytest
+//! maturin develop
+//! pytest
+//! ```
+//!
+//! ```python
+//! from kennitolur import Kennitala, validate_many
+//!
+//! kt = Kennitala("3110002920")
+//! print(kt, kt.birth_date)  # 3110002920 2000-10-31
+//! print(validate_many(["3110002920", "3110002930"]))
+//! # [None, "The kennitala's checksum is invalid"]
+//! ```
+use pyo3::exceptions::PyValueError;
+use pyo3::{create_exception, pymodule};
+
+create_exception!(
+    kennitolur,
+    KennitalaError,
+    PyValueError,
+    "Raised when a kennitala is invalid. A subclass of `ValueError`."
+);
+
+#[pymodule]
+mod kennitolur {
+    use chrono::NaiveDate;
+    use pyo3::prelude::*;
+
+    #[pymodule_export]
+    use super::KennitalaError;
+
+    /// A valid kennitala, an Icelandic government issued ID number. Raises
+    /// `KennitalaError` if the given string is not 10 digits forming a valid
+    /// kennitala.
+    #[pyclass(
+        name = "Kennitala",
+        module = "kennitolur",
+        frozen,
+        eq,
+        hash,
+        ord,
+        skip_from_py_object
+    )]
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct PyKennitala(::kennitolur::Kennitala);
+
+    #[pymethods]
+    impl PyKennitala {
+        #[new]
+        fn new(kennitala: &str) -> PyResult<Self> {
+            ::kennitolur::Kennitala::new(kennitala)
+                .map(PyKennitala)
+                .map_err(|e| KennitalaError::new_err(e.to_string()))
+        }
+
+        /// The date of birth, or of registration for companies, as a
+        /// `datetime.date`.
+        #[getter]
+        fn birth_date(&self) -> NaiveDate {
+            self.0.get_birthday()
+        }
+
+        /// Whether the kennitala belongs to a company.
+        #[getter]
+        fn is_company(&self) -> bool {
+            self.0.is_company()
+        }
+
+        fn __str__(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn __repr__(&self) -> String {
+            format!("Kennitala('{}')", self.0)
+        }
+    }
+
+    /// Validate each of the given strings, returning `None` for the valid
+    /// ones and the error message for the rest. The strings are validated
+    /// without holding the GIL.
+    #[pyfunction]
+    fn validate_many(py: Python<'_>, kennitolur: Vec<String>) -> Vec<Option<String>> {
+        py.detach(|| {
+            kennitolur
+                .iter()
+                .map(|kennitala| {
+                    ::kennitolur::validate(kennitala)
+                        .err()
+                        .map(|e| e.to_string())
+                })
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,82 @@
+from datetime import date
+import threading
+
+import pytest
+
+from kennitolur import Kennitala, KennitalaError, validate_many
+
+
+def test_valid():
+    kt = Kennitala("3110002920")
+    assert str(kt) == "3110002920"
+    assert repr(kt) == "Kennitala('3110002920')"
+    assert kt.birth_date == date(2000, 10, 31)
+    assert not kt.is_company
+
+
+def test_birth_date():
+    assert Kennitala("1703715939").birth_date == date(1971, 3, 17)
+    assert Kennitala("0311203149").birth_date == date(1920, 11, 3)
+    assert Kennitala("0101502998").birth_date == date(1850, 1, 1)
+    company = Kennitala("7112993009")
+    assert company.is_company
+    assert company.birth_date == date(1999, 12, 31)
+
+
+@pytest.mark.parametrize(
+    "kennitala, message",
+    [
+        ("3110002930", "The kennitala's checksum is invalid"),
+        ("311000", "Length 6 is invalid"),
+        ("311000-2920", "Invalid character '-' at position 6"),
+        ("3110002921", "Century of birth is invalid"),
+    ],
+)
+def test_invalid(kennitala, message):
+    with pytest.raises(KennitalaError, match=message):
+        Kennitala(kennitala)
+
+
+def test_error_is_value_error():
+    assert issubclass(KennitalaError, ValueError)
+    with pytest.raises(ValueError):
+        Kennitala("0000000000")
+
+
+def test_eq_and_hash():
+    a = Kennitala("3110002920")
+    b = Kennitala("3110002920")
+    c = Kennitala("1703715939")
+    assert a == b
+    assert a != c
+    assert hash(a) == hash(b)
+    assert len({a, b, c}) == 2
+    assert sorted([a, c]) == [c, a]
+
+
+def test_validate_many():
+    assert validate_many([]) == []
+    assert validate_many(["3110002920", "3110002930", "", "7112993009"]) == [
+        None,
+        "The kennitala's checksum is invalid",
+        "Length 0 is invalid",
+        None,
+    ]
+    with pytest.raises(TypeError):
+        validate_many(["3110002920", 3110002920])
+
+
+def test_validate_many_threads():
+    kennitolur = ["3110002920", "1703715939", "1703715949"] * 10_000
+    expected = [None, None, "The kennitala's checksum is invalid"] * 10_000
+    results = [None] * 4
+
+    def run(i):
+        results[i] = validate_many(kennitolur)
+
+    threads = [threading.Thread(target=run, args=(i,)) for i in range(4)]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+    assert results == [expected] * 4
@@ -0,0 +1,270 @@
+//! Extraction of kennitölur in `actix-web` handlers.
+//!
+//! `Kennitala` can be extracted directly, from the only parameter of the
+//! route's path, in which case an invalid kennitala is rejected with a
+//! `KennitalaResponseError`. This responds with `400 Bad Request` and a JSON
+//! body giving the name of the error's variant and its message, such as
+//! `{"error":"InvalidChecksum","message":"The kennitala's checksum is invalid"}`.
+//! Handlers which parse kennitölur themselves can return it as well.
+//!
+//! With the `serde` implementations, `Kennitala` can also be used in
+//! `web::Path`, `web::Query` and `web::Json` like any other deserializable
+//! type. Those extractors reject invalid kennitölur with their own responses,
+//! which for `web::Path` is `404 Not Found`, unless the app is set up with
+//! `configure`, which installs error handlers responding with a
+//! `KennitalaResponseError` instead:
+//!
+//! ```
+//! use actix_web::{web, App};
+//! use kennitolur::actix_web::KennitalaResponseError;
+//! use kennitolur::Kennitala;
+//!
+//! async fn person(kennitala: Kennitala) -> String {
+//!     format!("{:#}", kennitala)
+//! }
+//!
+//! async fn company(path: web::Path<Kennitala>) -> String {
+//!     format!("{:#}", path.into_inner())
+//! }
+//!
+//! async fn search(query: String) -> Result<String, KennitalaResponseError> {
+//!     let kennitala = Kennitala::new(query.trim())?;
+//!     Ok(format!("{:#}", kennitala))
+//! }
+//!
+//! let app = App::new()
+//!     .configure(kennitolur::actix_web::configure)
+//!     .route("/people/{kennitala}", web::get().to(person))
+//!     .route("/companies/{kennitala}", web::get().to(company))
+//!     .route("/search", web::post().to(search));
+//! ```
+use std::fmt;
+use std::future::{ready, Ready};
+
+use actix_web::dev::Payload;
+use actix_web::error::{
+    ErrorInternalServerError, ErrorNotFound, JsonPayloadError, PathError, QueryPayloadError,
+};
+use actix_web::http::StatusCode;
+use actix_web::web::{JsonConfig, PathConfig, QueryConfig, ServiceConfig};
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::{Kennitala, KennitalaError};
+
+/// A `KennitalaError` as an `actix-web` error, which responds with
+/// `400 Bad Request` and a JSON body with the name of the error's variant,
+/// such as `"InvalidChecksum"`, as `error`, and its message as `message`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct KennitalaResponseError(pub KennitalaError);
+
+impl fmt::Display for KennitalaResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<KennitalaError> for KennitalaResponseError {
+    fn from(e: KennitalaError) -> Self {
+        KennitalaResponseError(e)
+    }
+}
+
+impl ResponseError for KennitalaResponseError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ErrorBody(&self.0))
+    }
+}
+
+/// The JSON body of a `KennitalaResponseError`.
+struct ErrorBody<'a>(&'a KennitalaError);
+
+impl Serialize for ErrorBody<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut body = serializer.serialize_struct("KennitalaError", 2)?;
+        body.serialize_field("error", self.0.variant_name())?;
+        body.serialize_field("message", &self.0.to_string())?;
+        body.end()
+    }
+}
+
+impl FromRequest for Kennitala {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let mut params = req.match_info().iter();
+        let result = match (params.next(), params.next()) {
+            (Some((_, kennitala)), None) => {
+                Kennitala::new(kennitala).map_err(|e| KennitalaResponseError(e).into())
+            }
+            _ => Err(ErrorInternalServerError(
+                "a kennitala can only be extracted from a path with a single parameter",
+            )),
+        };
+        ready(result)
+    }
+}
+
+/// Set up the `web::Path`, `web::Query` and `web::Json` extractors to reject
+/// invalid kennitölur with a `KennitalaResponseError`, using
+/// `App::configure(kennitolur::actix_web::configure)`. Other errors are
+/// responded to as the extractors would otherwise. This replaces any
+/// `PathConfig`, `QueryConfig` and `JsonConfig` of the app; to keep their
+/// other settings, set the error handlers below on them instead.
+pub fn configure(config: &mut ServiceConfig) {
+    config
+        .app_data(PathConfig::default().error_handler(path_error_handler))
+        .app_data(QueryConfig::default().error_handler(query_error_handler))
+        .app_data(JsonConfig::default().error_handler(json_error_handler));
+}
+
+/// An error handler for `PathConfig`, responding with a
+/// `KennitalaResponseError` if the path holds an invalid kennitala, and with
+/// `404 Not Found` otherwise, as `web::Path` does without a handler.
+pub fn path_error_handler(error: PathError, _: &HttpRequest) -> actix_web::Error {
+    match error {
+        PathError::Deserialize(e) => match parse_error(&e.to_string()) {
+            Some(e) => KennitalaResponseError(e).into(),
+            None => ErrorNotFound(e),
+        },
+        error => error.into(),
+    }
+}
+
+/// An error handler for `QueryConfig`, responding with a
+/// `KennitalaResponseError` if the query holds an invalid kennitala.
+pub fn query_error_handler(error: QueryPayloadError, _: &HttpRequest) -> actix_web::Error {
+    match &error {
+        QueryPayloadError::Deserialize(e) => match parse_error(&e.to_string()) {
+            Some(e) => KennitalaResponseError(e).into(),
+            None => error.into(),
+        },
+        _ => error.into(),
+    }
+}
+
+/// An error handler for `JsonConfig`, responding with a
+/// `KennitalaResponseError` if the body holds an invalid kennitala.
+pub fn json_error_handler(error: JsonPayloadError, _: &HttpRequest) -> actix_web::Error {
+    if let JsonPayloadError::Deserialize(e) = &error {
+        // The message is followed by the position in the body.
+        let message = e.to_string();
+        let position = format!(" at line {} column {}", e.line(), e.column());
+        if let Some(e) = message.strip_suffix(&position).and_then(parse_error) {
+            return KennitalaResponseError(e).into();
+        }
+    }
+    error.into()
+}
+
+/// Recover the `KennitalaError` from the message of a deserialization error,
+/// which is all that the extractors keep of it.
+fn parse_error(message: &str) -> Option<KennitalaError> {
+    const WITHOUT_FIELDS: [KennitalaError; 8] = [
+        KennitalaError::InvalidDay,
+        KennitalaError::InvalidMonth,
+        KennitalaError::InvalidRandomDigits,
+        KennitalaError::InvalidChecksum,
+        KennitalaError::InvalidCentury,
+        KennitalaError::DateOutOfRange,
+        KennitalaError::Placeholder,
+        KennitalaError::InvalidRepresentation,
+    ];
+    let error = if let Some(length) = message
+        .strip_prefix("Length ")
+        .and_then(|m| m.strip_suffix(" is invalid"))
+    {
+        KennitalaError::InvalidLength(length.parse().ok()?)
+    } else if let Some(rest) = message.strip_prefix("Invalid character ") {
+        let (found, index) = rest.rsplit_once(" at position ")?;
+        KennitalaError::InvalidNumber {
+            index: index.parse().ok()?,
+            found: parse_char_literal(found)?,
+        }
+    } else {
+        *WITHOUT_FIELDS.iter().find(|e| e.to_string() == message)?
+    };
+    // Rule out messages which merely look like those of a `KennitalaError`.
+    Some(error).filter(|e| e.to_string() == message)
+}
+
+/// Parse a character as formatted with `{:?}`.
+fn parse_char_literal(literal: &str) -> Option<char> {
+    let inner = literal.strip_prefix('\'')?.strip_suffix('\'')?;
+    let mut chars = inner.chars();
+    match (chars.next()?, chars.next()) {
+        (c, None) => Some(c),
+        ('\\', Some('u')) => {
+            let hex = chars.as_str().strip_prefix('{')?.strip_suffix('}')?;
+            char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+        }
+        ('\\', Some(escaped)) if chars.as_str().is_empty() => match escaped {
+            't' => Some('\t'),
+            'r' => Some('\r'),
+            'n' => Some('\n'),
+            '0' => Some('\0'),
+            '\\' | '\'' | '"' => Some(escaped),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_round_trip() {
+        let errors = [
+            KennitalaError::InvalidLength(0),
+            KennitalaError::InvalidLength(123),
+            KennitalaError::InvalidNumber {
+                index: 5,
+                found: 'O',
+            },
+            KennitalaError::InvalidDay,
+            KennitalaError::InvalidMonth,
+            KennitalaError::InvalidRandomDigits,
+            KennitalaError::InvalidChecksum,
+            KennitalaError::InvalidCentury,
+            KennitalaError::DateOutOfRange,
+            KennitalaError::Placeholder,
+            KennitalaError::InvalidRepresentation,
+        ];
+        for e in &errors {
+            assert_eq!(parse_error(&e.to_string()), Some(*e));
+        }
+        for found in &[
+            '\'', '\\', '"', '\n', '\t', '\r', '\0', '\u{7f}', 'á', ' ', '-',
+        ] {
+            let e = KennitalaError::InvalidNumber {
+                index: 10,
+                found: *found,
+            };
+            assert_eq!(parse_error(&e.to_string()), Some(e), "{}", e);
+        }
+    }
+
+    #[test]
+    fn parse_error_other_messages() {
+        for message in &[
+            "",
+            "missing field `kennitala`",
+            "Length x is invalid",
+            "Invalid character 'ab' at position 1",
+            "Invalid character '\\q' at position 1",
+            "The kennitala's checksum is invalid at line 1 column 2",
+        ] {
+            assert_eq!(parse_error(message), None, "{}", message);
+        }
+    }
+}
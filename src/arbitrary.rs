@@ -0,0 +1,125 @@
+//! Generation of plausible kennitölur for structured fuzzing through
+//! `arbitrary`.
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::dates::days_in_month;
+use crate::{calculate_checksum_digit, COMPANY_DAY_OFFSET};
+
+/// A string of 10 digits which is usually a valid kennitala, or close to one.
+/// Unlike arbitrary strings, which are almost always rejected because of their
+/// length or characters, these exercise the validation of each field.
+///
+/// Most of the time each field is valid: the date exists, the random digits
+/// are in range, the century digit is `8`, `9` or `0`, and the checksum digit
+/// is correct. Otherwise a field is chosen from a wider range, or the checksum
+/// digit is off by one.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PlausibleKennitala(String);
+
+impl PlausibleKennitala {
+    /// Get the generated string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the generated string, consuming this.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for PlausibleKennitala {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> Arbitrary<'a> for PlausibleKennitala {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let century = if u.ratio(1, 10)? {
+            u.int_in_range(0..=9)?
+        } else {
+            *u.choose(&[8, 9, 0])?
+        };
+        let short_year: u32 = u.int_in_range(0..=99)?;
+        let month: u32 = if u.ratio(1, 10)? {
+            u.int_in_range(0..=19)?
+        } else {
+            u.int_in_range(1..=12)?
+        };
+        let year = match century {
+            8 => 1800,
+            9 => 1900,
+            _ => 2000,
+        } + short_year;
+        let mut day: u32 = match days_in_month(month, year) {
+            Some(days) if !u.ratio(1, 10)? => u.int_in_range(1..=u32::from(days))?,
+            _ => u.int_in_range(0..=39)?,
+        };
+        if u.arbitrary()? {
+            day += COMPANY_DAY_OFFSET;
+        }
+        let randoms: u32 = if u.ratio(1, 10)? {
+            u.int_in_range(0..=99)?
+        } else {
+            u.int_in_range(20..=99)?
+        };
+
+        let two_digits = |n: u32| [(n / 10) as u8, (n % 10) as u8];
+        let [day_0, day_1] = two_digits(day);
+        let [month_0, month_1] = two_digits(month);
+        let [year_0, year_1] = two_digits(short_year);
+        let [randoms_0, randoms_1] = two_digits(randoms);
+        let mut digits = [
+            day_0, day_1, month_0, month_1, year_0, year_1, randoms_0, randoms_1, 0, century,
+        ];
+        let checksum = calculate_checksum_digit(&digits);
+        digits[8] = if u.ratio(1, 10)? {
+            (checksum + *u.choose(&[1, 9])?) % 10
+        } else {
+            // A checksum of 10 can't be represented, so such kennitölur are
+            // never valid.
+            checksum % 10
+        };
+
+        Ok(PlausibleKennitala(
+            digits.iter().map(|d| char::from(b'0' + d)).collect(),
+        ))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(16))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Kennitala;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn mostly_valid() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut valid = 0;
+        for _ in 0..1000 {
+            let bytes: [u8; 32] = rng.random();
+            let mut u = Unstructured::new(&bytes);
+            let kt = PlausibleKennitala::arbitrary(&mut u).unwrap();
+            assert_eq!(kt.as_str().len(), 10);
+            assert!(kt.as_str().bytes().all(|b| b.is_ascii_digit()));
+            if Kennitala::new(kt.as_str()).is_ok() {
+                valid += 1;
+            }
+        }
+        assert!((300..1000).contains(&valid), "{}", valid);
+    }
+
+    #[test]
+    fn empty_input() {
+        let mut u = Unstructured::new(&[]);
+        let kt = PlausibleKennitala::arbitrary(&mut u).unwrap();
+        assert_eq!(kt.as_str().len(), 10);
+    }
+}
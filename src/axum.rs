@@ -0,0 +1,86 @@
+//! Extraction of kennitölur in `axum` handlers.
+//!
+//! With the `serde` implementations, `Kennitala` can be used in `Path`,
+//! `Query` and `Json` like any other deserializable type, in which case
+//! invalid kennitölur are rejected by those extractors. `Kennitala` can also
+//! be extracted directly, from the only parameter of the route's path, in
+//! which case an invalid kennitala is rejected with a
+//! `422 Unprocessable Entity` response giving the `KennitalaError` message.
+//! `Path<Kennitala>` rejects it with `400 Bad Request` instead, as `axum` does
+//! for any path which can not be deserialized:
+//!
+//! ```
+//! use axum::{routing::get, Router};
+//! use kennitolur::Kennitala;
+//!
+//! async fn person(kennitala: Kennitala) -> String {
+//!     format!("{:#}", kennitala)
+//! }
+//!
+//! let app: Router = Router::new().route("/people/{kennitala}", get(person));
+//! ```
+use std::error::Error;
+use std::fmt;
+
+use axum::extract::rejection::PathRejection;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use crate::{Kennitala, KennitalaError};
+
+/// Rejection used when a `Kennitala` can not be extracted from the path.
+#[derive(Debug)]
+pub enum KennitalaRejection {
+    /// The path parameter could not be extracted, for example because the
+    /// route has more than one parameter. This is responded to as `axum`
+    /// would for `Path<String>`.
+    Path(PathRejection),
+    /// The path parameter is not a valid kennitala. This is responded to with
+    /// `422 Unprocessable Entity` and the message of the error.
+    Invalid(KennitalaError),
+}
+
+impl fmt::Display for KennitalaRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KennitalaRejection::Path(rejection) => rejection.fmt(f),
+            KennitalaRejection::Invalid(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for KennitalaRejection {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KennitalaRejection::Path(rejection) => Some(rejection),
+            KennitalaRejection::Invalid(e) => Some(e),
+        }
+    }
+}
+
+impl IntoResponse for KennitalaRejection {
+    fn into_response(self) -> Response {
+        match self {
+            KennitalaRejection::Path(rejection) => rejection.into_response(),
+            KennitalaRejection::Invalid(e) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, e.to_string()).into_response()
+            }
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for Kennitala
+where
+    S: Send + Sync,
+{
+    type Rejection = KennitalaRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(kennitala) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(KennitalaRejection::Path)?;
+        Kennitala::new(&kennitala).map_err(KennitalaRejection::Invalid)
+    }
+}
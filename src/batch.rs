@@ -0,0 +1,74 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{Kennitala, KennitalaError};
+
+/// Inputs shorter than this are validated sequentially by `validate_all_par`,
+/// since splitting them up costs more than it saves.
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 4096;
+
+/// Validate each of the given kennitölur as `Kennitala::new` does. The results
+/// are in the same order as the input.
+///
+/// ```
+/// let results = kennitolur::validate_all(&["3110002920", "3110002930"]);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn validate_all(items: &[&str]) -> Vec<Result<Kennitala, KennitalaError>> {
+    items.iter().map(|item| Kennitala::new(item)).collect()
+}
+
+/// Validate each of the given kennitölur like `validate_all`, spread across
+/// threads with `rayon` for large inputs. The results are in the same order as
+/// the input.
+#[cfg(feature = "rayon")]
+pub fn validate_all_par(items: &[&str]) -> Vec<Result<Kennitala, KennitalaError>> {
+    if items.len() < PARALLEL_THRESHOLD {
+        return validate_all(items);
+    }
+    items.par_iter().map(|item| Kennitala::new(item)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITEMS: [&str; 5] = [
+        "3110002920",
+        "3110002930",
+        "1703715939",
+        "31100O2920",
+        "7112993009",
+    ];
+
+    #[test]
+    fn preserves_order() {
+        let results = validate_all(&ITEMS);
+        assert_eq!(results.len(), ITEMS.len());
+        for (item, result) in ITEMS.iter().zip(&results) {
+            assert_eq!(*result, Kennitala::new(item));
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(validate_all(&[]).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn parallel_agrees_with_sequential() {
+        let small = validate_all_par(&ITEMS);
+        assert_eq!(small, validate_all(&ITEMS));
+
+        let large: Vec<&str> = ITEMS
+            .iter()
+            .cycle()
+            .take(10 * PARALLEL_THRESHOLD)
+            .copied()
+            .collect();
+        assert_eq!(validate_all_par(&large), validate_all(&large));
+    }
+}
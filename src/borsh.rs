@@ -0,0 +1,115 @@
+//! Borsh serialization of kennitölur. A kennitala is written as its packed
+//! `u32`, as returned by `Kennitala::as_u32`, which documents the layout. Four
+//! bytes which are not the packed representation of a kennitala that
+//! `Kennitala::new` accepts are rejected with an `io::Error` of kind
+//! `InvalidData`. This includes kennitölur created with
+//! `Kennitala::new_unchecked_checksum` whose checksum is invalid.
+use std::io::{self, Read, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::Kennitala;
+
+impl BorshSerialize for Kennitala {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.as_u32().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Kennitala {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let packed = u32::deserialize_reader(reader)?;
+        Kennitala::from_packed(packed)
+            .filter(Kennitala::has_valid_checksum)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{:#010x} is not a packed kennitala", packed),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for kt_str in &["3110002920", "1703715939", "0311203149", "7112993009"] {
+            let kt = Kennitala::new(kt_str).unwrap();
+            let bytes = borsh::to_vec(&kt).unwrap();
+            assert_eq!(bytes, kt.as_u32().to_le_bytes());
+            assert_eq!(borsh::from_slice::<Kennitala>(&bytes).unwrap(), kt);
+        }
+    }
+
+    #[test]
+    fn in_struct() {
+        #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+        struct Message {
+            id: u64,
+            kennitala: Kennitala,
+            others: Vec<Kennitala>,
+        }
+
+        let message = Message {
+            id: 7,
+            kennitala: Kennitala::new("3110002920").unwrap(),
+            others: vec![
+                Kennitala::new("0311203149").unwrap(),
+                Kennitala::new("7112993009").unwrap(),
+            ],
+        };
+        let bytes = borsh::to_vec(&message).unwrap();
+        assert_eq!(bytes.len(), 8 + 4 + 4 + 2 * 4);
+        assert_eq!(borsh::from_slice::<Message>(&bytes).unwrap(), message);
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let error = borsh::from_slice::<Kennitala>(&u32::MAX.to_le_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "0xffffffff is not a packed kennitala");
+        assert!(borsh::from_slice::<Kennitala>(&[0, 0, 0, 0]).is_err());
+        assert!(borsh::from_slice::<Kennitala>(&[1, 2, 3]).is_err());
+
+        let kt = Kennitala::new_unchecked_checksum("3110002930").unwrap();
+        let bytes = borsh::to_vec(&kt).unwrap();
+        let error = borsh::from_slice::<Kennitala>(&bytes).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "0x2525015f is not a packed kennitala");
+    }
+
+    #[test]
+    fn adversarial_bytes() {
+        // Walk through the space of 4 byte patterns with a stride that is
+        // coprime to 2^32, along with every pattern close to a valid one.
+        let valid = Kennitala::new("3110002920").unwrap().as_u32();
+        let patterns = (0..1_000_000u32)
+            .map(|i| i.wrapping_mul(2_654_435_761))
+            .chain((0..32).map(|bit| valid ^ (1 << bit)))
+            .chain((0..32).flat_map(|a| (0..32).map(move |b| valid ^ (1 << a) ^ (1 << b))));
+        let mut accepted = 0;
+        let mut invalid_checksum = 0;
+        for packed in patterns {
+            let result = borsh::from_slice::<Kennitala>(&packed.to_le_bytes());
+            // Patterns which `from_packed` accepts only because they carry the
+            // invalid checksum flag must be rejected.
+            if Kennitala::from_packed(packed).is_some_and(|kt| !kt.has_valid_checksum()) {
+                invalid_checksum += 1;
+                assert!(result.is_err(), "{:#010x}", packed);
+                continue;
+            }
+            let kt = match result {
+                Ok(kt) => kt,
+                Err(_) => continue,
+            };
+            accepted += 1;
+            assert_eq!(kt.as_u32(), packed);
+            assert_eq!(Kennitala::new(&kt.to_string()), Ok(kt), "{:#010x}", packed);
+        }
+        assert!(invalid_checksum > 0);
+        assert!(accepted > 0);
+    }
+}
@@ -0,0 +1,298 @@
+//! Storage of kennitölur in MongoDB documents through `bson`.
+//!
+//! Kennitölur are best stored in BSON as their canonical 10 digit string,
+//! which keeps leading zeros and can be indexed and queried like any other
+//! string field. The `Serialize` and `Deserialize` implementations of
+//! `Kennitala` only do so with `bson::to_document` and `bson::from_document`.
+//! With `bson::to_vec` and `bson::from_slice`, which the MongoDB driver uses
+//! for typed collections, the format is not human-readable, so they write the
+//! packed `u32` documented in `Kennitala::as_u32` instead, and fail on strings.
+//! Fields should therefore use the `as_string` module, which stores the string
+//! in both cases, and accepts `Int32` and `Int64` values as well when
+//! deserializing. Collections which historically stored kennitölur as
+//! numbers, and should keep doing so, can use the `as_i64` module instead:
+//!
+//! ```
+//! use serde_derive::{Deserialize, Serialize};
+//! use kennitolur::Kennitala;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Person {
+//!     #[serde(with = "kennitolur::bson::as_string")]
+//!     kennitala: Kennitala,
+//!     #[serde(with = "kennitolur::bson::as_i64")]
+//!     legacy_kennitala: Kennitala,
+//! }
+//! ```
+//!
+//! Single values convert to and from `Bson` with `From` and `TryFrom`, which
+//! is convenient when building query filters.
+use core::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use bson::spec::ElementType;
+use bson::Bson;
+
+use crate::error::NegativeKennitala;
+use crate::{Kennitala, KennitalaError};
+
+/// Error converting a `Bson` value to a kennitala.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BsonError {
+    /// The value is neither a string nor an integer.
+    UnexpectedType(ElementType),
+    /// The value is a negative integer.
+    Negative(i64),
+    /// The value is not a valid kennitala.
+    Kennitala(KennitalaError),
+}
+
+impl fmt::Display for BsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BsonError::UnexpectedType(element_type) => write!(
+                f,
+                "Expected a kennitala as a string or an integer, found {:?}",
+                element_type
+            ),
+            BsonError::Negative(n) => NegativeKennitala(*n).fmt(f),
+            BsonError::Kennitala(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for BsonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BsonError::Kennitala(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<KennitalaError> for BsonError {
+    fn from(e: KennitalaError) -> Self {
+        BsonError::Kennitala(e)
+    }
+}
+
+impl From<Kennitala> for Bson {
+    fn from(kennitala: Kennitala) -> Self {
+        Bson::String(kennitala.to_string())
+    }
+}
+
+impl TryFrom<Bson> for Kennitala {
+    type Error = BsonError;
+
+    fn try_from(value: Bson) -> Result<Self, Self::Error> {
+        let kennitala = match value {
+            Bson::String(s) => Kennitala::new(&s)?,
+            Bson::Int32(n) => from_i64(i64::from(n))?,
+            Bson::Int64(n) => from_i64(n)?,
+            other => return Err(BsonError::UnexpectedType(other.element_type())),
+        };
+        Ok(kennitala)
+    }
+}
+
+fn from_i64(n: i64) -> Result<Kennitala, BsonError> {
+    let n = u64::try_from(n).map_err(|_| BsonError::Negative(n))?;
+    Ok(Kennitala::from_u64(n)?)
+}
+
+/// Serialize a kennitala as a BSON string, such as `"0311203149"`, and
+/// deserialize it from a string, an `Int32` or an `Int64`, with both
+/// `bson::to_document` and `bson::to_vec`. This is the same as
+/// `kennitolur::serde::flexible`.
+pub mod as_string {
+    pub use crate::serde::flexible::{deserialize, serialize};
+}
+
+/// Serialize a kennitala as a BSON `Int64`, such as `3110002920`, and
+/// deserialize it from an `Int32` or `Int64`. Leading zeros are lost, as with
+/// `Kennitala::to_decimal_u64`.
+pub mod as_i64 {
+    use core::convert::TryFrom;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::error::NegativeKennitala;
+    use crate::Kennitala;
+
+    /// Serialize the kennitala as an `i64`.
+    pub fn serialize<S>(kennitala: &Kennitala, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Ten decimal digits always fit in an i64.
+        serializer.serialize_i64(kennitala.to_decimal_u64() as i64)
+    }
+
+    /// Deserialize the kennitala from an `i64` with `Kennitala::from_u64`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Kennitala, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = i64::deserialize(deserializer)?;
+        let value =
+            u64::try_from(value).map_err(|_| de::Error::custom(NegativeKennitala(value)))?;
+        Kennitala::from_u64(value).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bson::{doc, from_document, from_slice, to_document, to_vec, Document};
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Person {
+        name: String,
+        kennitala: Kennitala,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct StoredPerson {
+        name: String,
+        #[serde(with = "as_string")]
+        kennitala: Kennitala,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct LegacyPerson {
+        #[serde(with = "as_i64")]
+        kennitala: Kennitala,
+    }
+
+    #[test]
+    fn document_round_trip() {
+        let person = Person {
+            name: "Jón".to_string(),
+            kennitala: Kennitala::new("0311203149").unwrap(),
+        };
+        let document = to_document(&person).unwrap();
+        assert_eq!(document, doc! { "name": "Jón", "kennitala": "0311203149" });
+        assert_eq!(from_document::<Person>(document).unwrap(), person);
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        let person = StoredPerson {
+            name: "Jón".to_string(),
+            kennitala: Kennitala::new("0311203149").unwrap(),
+        };
+        let bytes = to_vec(&person).unwrap();
+        assert_eq!(
+            from_slice::<Document>(&bytes).unwrap(),
+            doc! { "name": "Jón", "kennitala": "0311203149" }
+        );
+        assert_eq!(from_slice::<StoredPerson>(&bytes).unwrap(), person);
+        let document = to_document(&person).unwrap();
+        assert_eq!(document, doc! { "name": "Jón", "kennitala": "0311203149" });
+        assert_eq!(from_document::<StoredPerson>(document).unwrap(), person);
+
+        // Without `as_string`, only the document functions use the string.
+        let person = Person {
+            name: person.name,
+            kennitala: person.kennitala,
+        };
+        let bytes = to_vec(&person).unwrap();
+        assert_eq!(
+            from_slice::<Document>(&bytes).unwrap(),
+            doc! { "name": "Jón", "kennitala": i64::from(person.kennitala.as_u32()) }
+        );
+        assert_eq!(from_slice::<Person>(&bytes).unwrap(), person);
+    }
+
+    #[test]
+    fn raw_string_documents() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        for document in &[
+            doc! { "name": "Jón", "kennitala": "0311203149" },
+            doc! { "name": "Jón", "kennitala": 311203149_i32 },
+            doc! { "name": "Jón", "kennitala": 311203149_i64 },
+        ] {
+            let bytes = to_vec(document).unwrap();
+            let person = from_slice::<StoredPerson>(&bytes).unwrap();
+            assert_eq!(person.kennitala, kt, "{}", document);
+        }
+        let bytes = to_vec(&doc! { "name": "Jón", "kennitala": "0311203159" }).unwrap();
+        let error = from_slice::<StoredPerson>(&bytes).unwrap_err();
+        assert!(error.to_string().contains("checksum"), "{}", error);
+    }
+
+    #[test]
+    fn legacy_numeric_fields() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        let document = doc! { "name": "Jón", "kennitala": 311203149_i64 };
+        assert_eq!(from_document::<Person>(document).unwrap().kennitala, kt);
+        let document = doc! { "name": "Jón", "kennitala": 311203149_i32 };
+        assert_eq!(from_document::<Person>(document).unwrap().kennitala, kt);
+
+        let legacy = LegacyPerson {
+            kennitala: Kennitala::new("7112993009").unwrap(),
+        };
+        let document = to_document(&legacy).unwrap();
+        assert_eq!(document, doc! { "kennitala": 7112993009_i64 });
+        assert_eq!(from_document::<LegacyPerson>(document).unwrap(), legacy);
+        let document = doc! { "kennitala": 311203149_i32 };
+        assert_eq!(
+            from_document::<LegacyPerson>(document).unwrap().kennitala,
+            kt
+        );
+    }
+
+    #[test]
+    fn malformed_documents() {
+        let documents = [
+            doc! { "name": "Jón", "kennitala": "0311203159" },
+            doc! { "name": "Jón", "kennitala": 311203159_i64 },
+            doc! { "name": "Jón", "kennitala": -311203149_i64 },
+            doc! { "name": "Jón", "kennitala": true },
+            doc! { "name": "Jón" },
+        ];
+        for document in &documents {
+            assert!(
+                from_document::<Person>(document.clone()).is_err(),
+                "{}",
+                document
+            );
+        }
+        let error = from_document::<LegacyPerson>(doc! { "kennitala": "0311203149" })
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("i64"), "{}", error);
+        let error = from_document::<LegacyPerson>(doc! { "kennitala": -1_i64 })
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "Kennitala can not be negative, found -1");
+    }
+
+    #[test]
+    fn bson_values() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        assert_eq!(Bson::from(kt), Bson::String("0311203149".to_string()));
+        assert_eq!(Kennitala::try_from(Bson::from(kt)), Ok(kt));
+        assert_eq!(Kennitala::try_from(Bson::Int32(311203149)), Ok(kt));
+        assert_eq!(Kennitala::try_from(Bson::Int64(311203149)), Ok(kt));
+        assert_eq!(
+            Kennitala::try_from(Bson::String("0311203159".to_string())),
+            Err(BsonError::Kennitala(KennitalaError::InvalidChecksum))
+        );
+        assert_eq!(
+            Kennitala::try_from(Bson::Int64(-311203149)),
+            Err(BsonError::Negative(-311203149))
+        );
+        assert_eq!(
+            Kennitala::try_from(Bson::Boolean(true)),
+            Err(BsonError::UnexpectedType(ElementType::Boolean))
+        );
+        assert_eq!(
+            BsonError::UnexpectedType(ElementType::Double).to_string(),
+            "Expected a kennitala as a string or an integer, found Double"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Casting of kennitölur to and from their packed representation with
+//! `bytemuck`. `Kennitala` is `#[repr(transparent)]` over the `u32` returned by
+//! `Kennitala::as_u32`, so every bit pattern, including zero, is safe to cast
+//! to a kennitala. That does not make it a *valid* kennitala: casting from
+//! untrusted data should be followed by `validate_raw` on each value, or done
+//! with `Kennitala::from_packed` instead.
+//!
+//! ```
+//! use kennitolur::{validate_raw, Kennitala};
+//!
+//! let packed: Vec<u32> = ["3110002920", "1703715939"]
+//!     .iter()
+//!     .map(|s| Kennitala::new(s).unwrap().as_u32())
+//!     .collect();
+//! let kennitolur: &[Kennitala] = bytemuck::cast_slice(&packed);
+//! assert_eq!(kennitolur[1].to_string(), "1703715939");
+//! assert!(packed.iter().all(|p| validate_raw(*p).is_ok()));
+//! ```
+// The implementations below are sound because `Kennitala` is a
+// `#[repr(transparent)]` wrapper around a `u32`, and no code relies on the
+// packed value being valid for memory safety.
+#![allow(unsafe_code)]
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::Kennitala;
+
+unsafe impl Zeroable for Kennitala {}
+
+unsafe impl Pod for Kennitala {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{validate_raw, KennitalaError};
+
+    #[test]
+    fn cast_slices() {
+        let kennitolur: Vec<Kennitala> = ["3110002920", "1703715939", "0311203149", "7112993009"]
+            .iter()
+            .map(|s| Kennitala::new(s).unwrap())
+            .collect();
+        let packed: &[u32] = bytemuck::cast_slice(&kennitolur);
+        let expected: Vec<u32> = kennitolur.iter().map(Kennitala::as_u32).collect();
+        assert_eq!(packed, &expected[..]);
+
+        let cast: &[Kennitala] = bytemuck::cast_slice(packed);
+        assert_eq!(cast, &kennitolur[..]);
+        for (kt, packed) in cast.iter().zip(packed) {
+            assert_eq!(validate_raw(*packed), Ok(*kt));
+        }
+
+        let bytes: &[u8] = bytemuck::cast_slice(&kennitolur);
+        assert_eq!(bytes.len(), 4 * kennitolur.len());
+    }
+
+    #[test]
+    fn zeroed_is_not_valid() {
+        let kt: Kennitala = Zeroable::zeroed();
+        assert_eq!(kt, Kennitala::from_raw_unvalidated(0));
+        assert!(validate_raw(kt.as_u32()).is_err());
+        assert_eq!(
+            validate_raw(bytemuck::cast(kt)),
+            Err(KennitalaError::InvalidRandomDigits)
+        );
+    }
+}
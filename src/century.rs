@@ -0,0 +1,157 @@
+use crate::{calculate_checksum_digit, Kennitala, KennitalaError};
+
+/// The century in which the holder of a kennitala was born, as indicated by
+/// the last digit of the kennitala.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Century {
+    /// The 1800s, indicated by the digit `8`.
+    Nineteenth,
+    /// The 1900s, indicated by the digit `9`.
+    Twentieth,
+    /// The 2000s, indicated by the digit `0`.
+    TwentyFirst,
+}
+
+impl Century {
+    /// All centuries, in chronological order.
+    pub const ALL: [Century; 3] = [
+        Century::Nineteenth,
+        Century::Twentieth,
+        Century::TwentyFirst,
+    ];
+
+    /// Get the digit which indicates this century in a kennitala.
+    pub fn digit(self) -> u8 {
+        match self {
+            Century::Nineteenth => 8,
+            Century::Twentieth => 9,
+            Century::TwentyFirst => 0,
+        }
+    }
+}
+
+impl Kennitala {
+    /// Reconstruct a kennitala whose century digit is missing, as found in some
+    /// legacy records. The given digits are either the first 9 digits of a
+    /// kennitala, such as `311000292`, in which case the checksum digit is
+    /// validated, or just the first 8, such as `311000-29`, in which case the
+    /// checksum digit is calculated. A hyphen is allowed after the sixth digit.
+    pub fn from_partial(digits: &str, century: Century) -> Result<Self, KennitalaError> {
+        let (mut kennitala, length) = partial_to_array(digits)?;
+        if length == 8 {
+            kennitala[8] = calculate_checksum_digit(&kennitala);
+            if kennitala[8] > 9 {
+                return Err(KennitalaError::InvalidChecksum);
+            }
+        }
+        kennitala[9] = century.digit();
+        Kennitala::from_digits(kennitala)
+    }
+
+    /// Get every kennitala which the given partial kennitala, as accepted by
+    /// `Kennitala::from_partial`, could be reconstructed into. More than one
+    /// candidate means that the century of birth is ambiguous.
+    #[cfg(feature = "std")]
+    pub fn candidates(digits: &str) -> Vec<Kennitala> {
+        Century::ALL
+            .iter()
+            .filter_map(|century| Kennitala::from_partial(digits, *century).ok())
+            .collect()
+    }
+}
+
+/// Convert the given partial kennitala to an array of digits with the century
+/// digit, and possibly the checksum digit, left as zero. Also returns the
+/// number of digits given.
+fn partial_to_array(digits: &str) -> Result<([u8; 10], usize), KennitalaError> {
+    let mut kennitala = [0; 10];
+    let mut length = 0;
+    for (index, c) in digits.char_indices() {
+        if (c == '-') && (index == 6) {
+            continue;
+        }
+        let digit = c
+            .to_digit(10)
+            .ok_or(KennitalaError::InvalidNumber { index, found: c })?;
+        if let Some(d) = kennitala.get_mut(length) {
+            *d = digit as u8;
+        }
+        length += 1;
+    }
+    if !(8..=9).contains(&length) {
+        return Err(KennitalaError::InvalidLength(length));
+    }
+    Ok((kennitala, length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_partial() {
+        let kt = Kennitala::new("3110002920").unwrap();
+        assert_eq!(
+            Kennitala::from_partial("311000292", Century::TwentyFirst).unwrap(),
+            kt
+        );
+        assert_eq!(
+            Kennitala::from_partial("311000-292", Century::TwentyFirst).unwrap(),
+            kt
+        );
+        assert_eq!(
+            Kennitala::from_partial("31100029", Century::TwentyFirst).unwrap(),
+            kt
+        );
+        assert_eq!(
+            Kennitala::from_partial("311000-29", Century::TwentyFirst).unwrap(),
+            kt
+        );
+
+        let kt = Kennitala::from_partial("170371-59", Century::Twentieth).unwrap();
+        assert_eq!(kt.to_string(), "1703715939");
+        let kt = Kennitala::from_partial("17037159", Century::Nineteenth).unwrap();
+        assert_eq!(kt.to_string(), "1703715938");
+    }
+
+    #[test]
+    fn from_partial_invalid() {
+        let kt = Kennitala::from_partial("311000293", Century::TwentyFirst);
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
+        let kt = Kennitala::from_partial("3110002", Century::TwentyFirst);
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(7)));
+        let kt = Kennitala::from_partial("3110002920", Century::TwentyFirst);
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(10)));
+        let kt = Kennitala::from_partial("3110-0029", Century::TwentyFirst);
+        assert!(matches!(kt, Err(KennitalaError::InvalidNumber { .. })));
+        // The random digits 25 result in a checksum of 10.
+        let kt = Kennitala::from_partial("31100025", Century::TwentyFirst);
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn candidates() {
+        let candidates: Vec<String> = Kennitala::candidates("311000-29")
+            .iter()
+            .map(|kt| kt.to_string())
+            .collect();
+        assert_eq!(candidates, ["3110002928", "3110002929", "3110002920"]);
+
+        // The 29th of February 2000 was a leap day, unlike in 1800 and 1900.
+        let candidates = Kennitala::candidates("290200-22");
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].get_year(), 2000);
+
+        assert!(Kennitala::candidates("320100-22").is_empty());
+        assert!(Kennitala::candidates("not a kennitala").is_empty());
+    }
+
+    #[test]
+    fn digits() {
+        for century in &Century::ALL {
+            let kt = Kennitala::from_partial("010150-29", *century).unwrap();
+            assert_eq!(kt.get_short_century(), u32::from(century.digit()));
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Parsing of kennitölur in command line arguments with `clap`. Arguments of
+//! type `Kennitala` use `KennitalaValueParser` by default, both with the
+//! builder and the derive API, and accept a kennitala with or without a
+//! hyphen after the sixth digit:
+//!
+//! ```
+//! use clap::{value_parser, Arg, Command};
+//! use kennitolur::Kennitala;
+//!
+//! let cmd = Command::new("lookup")
+//!     .arg(Arg::new("kennitala").value_parser(value_parser!(Kennitala)));
+//! let matches = cmd.try_get_matches_from(["lookup", "311000-2920"]).unwrap();
+//! let kennitala: &Kennitala = matches.get_one("kennitala").unwrap();
+//! assert_eq!(kennitala.to_string(), "3110002920");
+//! ```
+use std::ffi::OsStr;
+
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command, Error};
+
+use crate::Kennitala;
+
+/// A `clap` value parser for kennitölur, which accepts them as with
+/// `Kennitala::new_lenient`. Invalid values are reported with the
+/// `KennitalaError` message, along with a hint about the expected form.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct KennitalaValueParser;
+
+impl KennitalaValueParser {
+    /// Create a new value parser.
+    pub fn new() -> Self {
+        KennitalaValueParser
+    }
+}
+
+impl TypedValueParser for KennitalaValueParser {
+    type Value = Kennitala;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Kennitala, Error> {
+        let arg = arg.map_or_else(|| "...".to_string(), Arg::to_string);
+        let value = value.to_str().ok_or_else(|| {
+            cmd.clone().error(
+                ErrorKind::InvalidUtf8,
+                format!("invalid UTF-8 in the kennitala for '{}'", arg),
+            )
+        })?;
+        Kennitala::new_lenient(value).map_err(|e| {
+            cmd.clone().error(
+                ErrorKind::ValueValidation,
+                format!(
+                    "invalid value '{}' for '{}': {}\n\n  \
+                     tip: a kennitala is 10 digits, optionally with a hyphen \
+                     after the sixth, such as '311000-2920'",
+                    value, arg, e
+                ),
+            )
+        })
+    }
+}
+
+impl ValueParserFactory for Kennitala {
+    type Parser = KennitalaValueParser;
+
+    fn value_parser() -> Self::Parser {
+        KennitalaValueParser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{value_parser, Parser};
+
+    fn command() -> Command {
+        Command::new("lookup")
+            .arg(Arg::new("kennitala").value_parser(KennitalaValueParser::new()))
+            .arg(
+                Arg::new("spouse")
+                    .long("spouse")
+                    .value_parser(value_parser!(Kennitala)),
+            )
+    }
+
+    fn parse(args: &[&str]) -> Result<(Kennitala, Option<Kennitala>), String> {
+        let matches = command()
+            .try_get_matches_from(args)
+            .map_err(|e| e.to_string())?;
+        let kennitala = *matches.get_one::<Kennitala>("kennitala").unwrap();
+        let spouse = matches.get_one::<Kennitala>("spouse").copied();
+        Ok((kennitala, spouse))
+    }
+
+    #[test]
+    fn valid_arguments() {
+        let kt = Kennitala::new("3110002920").unwrap();
+        let spouse = Kennitala::new("1703715939").unwrap();
+        assert_eq!(parse(&["lookup", "3110002920"]), Ok((kt, None)));
+        assert_eq!(parse(&["lookup", "311000-2920"]), Ok((kt, None)));
+        assert_eq!(
+            parse(&["lookup", "3110002920", "--spouse", "170371-5939"]),
+            Ok((kt, Some(spouse)))
+        );
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        assert_eq!(
+            parse(&["lookup", "3110002930"]).unwrap_err(),
+            "error: invalid value '3110002930' for '[kennitala]': \
+             The kennitala's checksum is invalid\n\
+             \n  tip: a kennitala is 10 digits, optionally with a hyphen after \
+             the sixth, such as '311000-2920'\n\
+             \nUsage: lookup [OPTIONS] [kennitala]\n\
+             \nFor more information, try '--help'.\n"
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            parse(&["lookup", "3110002920", "--spouse", "31100-02920"]).unwrap_err(),
+            "error: invalid value '31100-02920' for '--spouse <spouse>': \
+             Invalid character '-' at position 5\n\
+             \n  tip: a kennitala is 10 digits, optionally with a hyphen after \
+             the sixth, such as '311000-2920'\n\
+             \nUsage: lookup [OPTIONS] [kennitala]\n\
+             \nFor more information, try '--help'.\n"
+        );
+        let error = parse(&["lookup", "311000"]).unwrap_err();
+        assert!(error.contains(": Length 6 is invalid\n"), "{}", error);
+    }
+
+    #[test]
+    fn derive() {
+        #[derive(Debug, Parser)]
+        struct Lookup {
+            kennitala: Kennitala,
+            #[arg(long)]
+            spouse: Option<Kennitala>,
+        }
+
+        let lookup = Lookup::try_parse_from(["lookup", "031120-3149"]).unwrap();
+        assert_eq!(lookup.kennitala, Kennitala::new("0311203149").unwrap());
+        assert_eq!(lookup.spouse, None);
+        let error = Lookup::try_parse_from(["lookup", "0311203159"])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.starts_with(
+                "error: invalid value '0311203159' for '<KENNITALA>': \
+                 The kennitala's checksum is invalid"
+            ),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,308 @@
+//! Validation of the kennitala column of a CSV file.
+//!
+//! ```
+//! let data = "name,kennitala\nJón,311000-2920\nGuðrún,1703715939\nAnna,3110002930\n";
+//! let report = kennitolur::csv::validate_column(data.as_bytes(), "kennitala").unwrap();
+//! assert_eq!(report.rows(), 3);
+//! assert_eq!(report.failures().len(), 1);
+//! assert_eq!(report.failures()[0].row(), 3);
+//! ```
+use std::error::Error;
+use std::fmt;
+use std::io::Read;
+
+use csv::{ByteRecord, ReaderBuilder};
+
+use crate::{validate, ErrorCounts, Kennitala, KennitalaError};
+
+/// Errors which prevent the column of a CSV file from being validated.
+#[derive(Debug)]
+pub enum ColumnError {
+    /// The file could not be read, or is not valid CSV.
+    Csv(csv::Error),
+    /// The file has no column with the given header.
+    MissingColumn(String),
+}
+
+impl fmt::Display for ColumnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnError::Csv(e) => e.fmt(f),
+            ColumnError::MissingColumn(column) => write!(f, "There is no column {:?}", column),
+        }
+    }
+}
+
+impl Error for ColumnError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ColumnError::Csv(e) => Some(e),
+            ColumnError::MissingColumn(_) => None,
+        }
+    }
+}
+
+impl From<csv::Error> for ColumnError {
+    fn from(e: csv::Error) -> Self {
+        ColumnError::Csv(e)
+    }
+}
+
+/// A row whose kennitala is invalid.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Failure {
+    row: u64,
+    value: String,
+    error: KennitalaError,
+}
+
+impl Failure {
+    /// Get the 1-based number of the row, not counting the header.
+    pub fn row(&self) -> u64 {
+        self.row
+    }
+
+    /// Get the value of the kennitala column, as given. Bytes which are not
+    /// valid UTF-8 are replaced with `char::REPLACEMENT_CHARACTER`.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the reason why the kennitala is invalid.
+    pub fn error(&self) -> KennitalaError {
+        self.error
+    }
+}
+
+/// The result of validating the kennitala column of a CSV file.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ValidationReport {
+    rows: u64,
+    failures: Vec<Failure>,
+    error_counts: ErrorCounts,
+}
+
+impl ValidationReport {
+    /// Get the number of rows, not counting the header.
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+
+    /// Get the number of rows with a valid kennitala.
+    pub fn valid(&self) -> u64 {
+        self.rows - self.error_counts.total()
+    }
+
+    /// Get every row with an invalid kennitala, in the order of the file.
+    pub fn failures(&self) -> &[Failure] {
+        &self.failures
+    }
+
+    /// Get the number of failures of each `KennitalaError` variant.
+    pub fn error_counts(&self) -> &ErrorCounts {
+        &self.error_counts
+    }
+
+    /// Whether every row has a valid kennitala.
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Validate the column with the given header in the given CSV file. The
+/// kennitölur may contain a hyphen between the sixth and seventh digit. The
+/// file is read one row at a time, so it need not fit in memory, but a
+/// byte order mark at its start is ignored.
+pub fn validate_column<R: Read>(reader: R, column: &str) -> Result<ValidationReport, ColumnError> {
+    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(reader);
+    let index = reader
+        .byte_headers()?
+        .iter()
+        .position(|header| header == column.as_bytes())
+        .ok_or_else(|| ColumnError::MissingColumn(column.to_string()))?;
+    validate_records(reader, index)
+}
+
+/// Validate the column with the given 0-based index in the given CSV file,
+/// which has no header. Otherwise, this behaves like `validate_column`.
+pub fn validate_column_index<R: Read>(
+    reader: R,
+    index: usize,
+) -> Result<ValidationReport, ColumnError> {
+    let reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
+    validate_records(reader, index)
+}
+
+fn validate_records<R: Read>(
+    mut reader: csv::Reader<R>,
+    index: usize,
+) -> Result<ValidationReport, ColumnError> {
+    let mut report = ValidationReport::default();
+    let mut record = ByteRecord::new();
+    while reader.read_byte_record(&mut record)? {
+        report.rows += 1;
+        let value = record.get(index).unwrap_or_default();
+        if let Err(error) = validate_field(value) {
+            report.error_counts.add(&error);
+            report.failures.push(Failure {
+                row: report.rows,
+                value: String::from_utf8_lossy(value).into_owned(),
+                error,
+            });
+        }
+    }
+    Ok(report)
+}
+
+/// Validate the given field, which may contain a hyphen between the sixth and
+/// seventh digit.
+fn validate_field(field: &[u8]) -> Result<(), KennitalaError> {
+    match std::str::from_utf8(field) {
+        Ok(field) if field.len() == 11 => Kennitala::new_lenient(field).map(|_| ()),
+        Ok(field) => validate(field),
+        Err(_) => Kennitala::from_bytes(field).map(|_| ()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn headers() {
+        let data = "\
+nafn,kennitala,heimili
+Jón Jónsson,3110002920,Reykjavík
+\"Jónsdóttir, Guðrún\",\"170371-5939\",Akureyri
+Ægir hf.,7112993009,\"Hafnarfirði
+Íslandi\"
+Anna,3110002930,Reykjavík
+Sigga,,Reykjavík
+Palli,31100O2920,Reykjavík
+";
+        let report = validate_column(data.as_bytes(), "kennitala").unwrap();
+        assert_eq!(report.rows(), 6);
+        assert_eq!(report.valid(), 3);
+        assert!(!report.is_valid());
+        let failures: Vec<_> = report
+            .failures()
+            .iter()
+            .map(|failure| (failure.row(), failure.value(), failure.error()))
+            .collect();
+        assert_eq!(
+            failures,
+            [
+                (4, "3110002930", KennitalaError::InvalidChecksum),
+                (5, "", KennitalaError::InvalidLength(0)),
+                (
+                    6,
+                    "31100O2920",
+                    KennitalaError::InvalidNumber {
+                        index: 5,
+                        found: 'O'
+                    }
+                ),
+            ]
+        );
+        let counts = report.error_counts();
+        assert_eq!(counts.get(&KennitalaError::InvalidChecksum), 1);
+        assert_eq!(counts.get(&KennitalaError::InvalidLength(0)), 1);
+        assert_eq!(counts.total(), 3);
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        let data = "\u{feff}kennitala,nafn\n3110002920,Jón\n";
+        let report = validate_column(data.as_bytes(), "kennitala").unwrap();
+        assert_eq!(report.rows(), 1);
+        assert!(report.is_valid());
+
+        let data = "\u{feff}3110002920,Jón\n1703715939,Guðrún\n";
+        let report = validate_column_index(data.as_bytes(), 0).unwrap();
+        assert_eq!(report.rows(), 2);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn without_headers() {
+        let data = "Jón,3110002920\r\nGuðrún,1703715939\r\nAnna,9999\r\n";
+        let report = validate_column_index(data.as_bytes(), 1).unwrap();
+        assert_eq!(report.rows(), 3);
+        assert_eq!(report.failures().len(), 1);
+        assert_eq!(report.failures()[0].value(), "9999");
+        assert_eq!(report.failures()[0].row(), 3);
+    }
+
+    #[test]
+    fn missing_column() {
+        let data = "nafn,kt\nJón,3110002920\n";
+        let report = validate_column(data.as_bytes(), "kennitala");
+        assert!(matches!(report, Err(ColumnError::MissingColumn(column)) if column == "kennitala"));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let data = b"kennitala,nafn\n3110002920,J\xf3n\n31100\xd02920,Gu\xf0r\xfan\n";
+        let report = validate_column(&data[..], "kennitala").unwrap();
+        assert_eq!(report.rows(), 2);
+        assert_eq!(report.failures()[0].value(), "31100\u{fffd}2920");
+        assert_eq!(
+            report.failures()[0].error(),
+            KennitalaError::InvalidNumber {
+                index: 5,
+                found: char::REPLACEMENT_CHARACTER
+            }
+        );
+    }
+
+    /// A CSV file with the given number of rows, generated while it is read,
+    /// where every 1000th row has an invalid kennitala.
+    struct GeneratedRows {
+        rows: u64,
+        row: u64,
+        line: Vec<u8>,
+        position: usize,
+    }
+
+    impl Read for GeneratedRows {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.position == self.line.len() {
+                if self.row == self.rows {
+                    return Ok(0);
+                }
+                self.row += 1;
+                let kennitala = if self.row.is_multiple_of(1000) {
+                    "3110002930"
+                } else {
+                    "3110002920"
+                };
+                self.line = format!("{},{}\n", self.row, kennitala).into_bytes();
+                self.position = 0;
+            }
+            let n = (self.line.len() - self.position).min(buf.len());
+            buf[..n].copy_from_slice(&self.line[self.position..self.position + n]);
+            self.position += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn million_rows() {
+        let rows = GeneratedRows {
+            rows: 1_000_000,
+            row: 0,
+            line: b"id,kennitala\n".to_vec(),
+            position: 0,
+        };
+        let report = validate_column(rows, "kennitala").unwrap();
+        assert_eq!(report.rows(), 1_000_000);
+        assert_eq!(report.valid(), 999_000);
+        assert_eq!(report.failures().len(), 1000);
+        assert_eq!(report.failures()[0].row(), 1000);
+        assert_eq!(
+            report.error_counts().get(&KennitalaError::InvalidChecksum),
+            1000
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::dates::days_in_month;
+use crate::{Kennitala, KennitalaError, COMPANY_DAY_OFFSET, VALIDATION_DIGITS};
+
+/// The first six digits of a kennitala, giving the date of birth or
+/// registration, validated once to parse many kennitölur which share them with
+/// `Kennitala::new_with_cached_date`. This is useful when processing sorted
+/// kennitölur, where long runs share the same date.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DatePrefix {
+    digits: [u8; 6],
+    /// The weighted sum of the digits, which the checksum is calculated from.
+    sum: u32,
+    /// Whether the date exists in the century given by each century digit.
+    /// Only the 29th of February depends on the century.
+    centuries: [bool; 10],
+}
+
+impl DatePrefix {
+    /// Parse the first six digits of a kennitala, such as `"311000"`. The
+    /// errors are those `Kennitala::new` would return for the date, except that
+    /// the length is that of the prefix. A date which only exists in some
+    /// centuries, such as `"290200"`, is accepted, and checked against the
+    /// century digit once it is known.
+    pub fn parse(prefix: &str) -> Result<Self, KennitalaError> {
+        let bytes = prefix.as_bytes();
+        if let Some(index) = bytes.iter().position(|b| !b.is_ascii_digit()) {
+            return Err(invalid_number(prefix, index, 0));
+        }
+        if bytes.len() != 6 {
+            return Err(KennitalaError::InvalidLength(bytes.len()));
+        }
+
+        let mut digits = [0; 6];
+        for (digit, byte) in digits.iter_mut().zip(bytes) {
+            *digit = byte - b'0';
+        }
+        let month = u32::from(digits[2] * 10 + digits[3]);
+        if days_in_month(month, 2001).is_none() {
+            return Err(KennitalaError::InvalidMonth);
+        }
+        let short_year = u32::from(digits[4] * 10 + digits[5]);
+        let mut day = u32::from(digits[0] * 10 + digits[1]);
+        if day > COMPANY_DAY_OFFSET {
+            day -= COMPANY_DAY_OFFSET;
+        }
+
+        let mut centuries = [false; 10];
+        for (digit, century) in &[(8, 1800), (9, 1900), (0, 2000)] {
+            centuries[*digit] = day != 0
+                && days_in_month(month, century + short_year)
+                    .is_some_and(|days| day <= u32::from(days));
+        }
+        if !centuries.contains(&true) {
+            return Err(KennitalaError::InvalidDay);
+        }
+
+        let sum = digits
+            .iter()
+            .zip(&VALIDATION_DIGITS)
+            .map(|(digit, weight)| u32::from(digit * weight))
+            .sum();
+        Ok(DatePrefix {
+            digits,
+            sum,
+            centuries,
+        })
+    }
+}
+
+impl Kennitala {
+    /// Create new kennitala object from a prefix parsed with
+    /// `DatePrefix::parse` and the remaining 4 digits, such as `"2920"`,
+    /// without validating the date again. This gives the same result as
+    /// `Kennitala::new` on the whole kennitala, with indices and lengths in
+    /// errors counted from the start of the prefix.
+    pub fn new_with_cached_date(prefix: &DatePrefix, tail: &str) -> Result<Self, KennitalaError> {
+        let bytes = tail.as_bytes();
+        if let Some(index) = bytes.iter().position(|b| !b.is_ascii_digit()) {
+            return Err(invalid_number(tail, index, 6));
+        }
+        if bytes.len() != 4 {
+            return Err(KennitalaError::InvalidLength(6 + bytes.len()));
+        }
+        let tail = [
+            bytes[0] - b'0',
+            bytes[1] - b'0',
+            bytes[2] - b'0',
+            bytes[3] - b'0',
+        ];
+
+        let d = prefix.digits;
+        if d.iter().chain(&tail).all(|digit| *digit == d[0]) {
+            return Err(KennitalaError::Placeholder);
+        }
+
+        let sum = prefix.sum
+            + u32::from(tail[0] * VALIDATION_DIGITS[6])
+            + u32::from(tail[1] * VALIDATION_DIGITS[7]);
+        let checksum = match sum % 11 {
+            0 => 0,
+            r => 11 - r,
+        };
+        if checksum != u32::from(tail[2]) {
+            return Err(KennitalaError::InvalidChecksum);
+        }
+        if tail[0] * 10 + tail[1] < 20 {
+            return Err(KennitalaError::InvalidRandomDigits);
+        }
+        if !matches!(tail[3], 8 | 9 | 0) {
+            return Err(KennitalaError::InvalidCentury);
+        }
+        if !prefix.centuries[usize::from(tail[3])] {
+            return Err(KennitalaError::InvalidDay);
+        }
+
+        let kennitala = [
+            d[0], d[1], d[2], d[3], d[4], d[5], tail[0], tail[1], tail[2], tail[3],
+        ];
+        Ok(Kennitala::pack(&kennitala, true))
+    }
+}
+
+/// Create an `InvalidNumber` error for the character at the given byte index of
+/// the given string, which is preceded by `offset` other characters.
+fn invalid_number(s: &str, index: usize, offset: usize) -> KennitalaError {
+    // Every byte before the index is an ASCII digit, so it is a char boundary.
+    let found = s[index..]
+        .chars()
+        .next()
+        .unwrap_or(char::REPLACEMENT_CHARACTER);
+    KennitalaError::InvalidNumber {
+        index: offset + index,
+        found,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agrees_with_new() {
+        for prefix in &[
+            "311000", "170371", "290200", "290296", "290204", "711299", "111111",
+        ] {
+            let date = DatePrefix::parse(prefix).unwrap();
+            for n in 0..10000 {
+                let tail = format!("{:04}", n);
+                let kennitala = format!("{}{}", prefix, tail);
+                assert_eq!(
+                    Kennitala::new_with_cached_date(&date, &tail),
+                    Kennitala::new(&kennitala),
+                    "{}",
+                    kennitala
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_tail() {
+        let date = DatePrefix::parse("311000").unwrap();
+        for tail in &["292", "29200", "292a", "29-20", "29é0", ""] {
+            let kennitala = format!("311000{}", tail);
+            assert_eq!(
+                Kennitala::new_with_cached_date(&date, tail),
+                Kennitala::new(&kennitala)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_prefix() {
+        let cases = [
+            ("31100", KennitalaError::InvalidLength(5)),
+            ("3110002", KennitalaError::InvalidLength(7)),
+            (
+                "31x000",
+                KennitalaError::InvalidNumber {
+                    index: 2,
+                    found: 'x',
+                },
+            ),
+            ("311300", KennitalaError::InvalidMonth),
+            ("310000", KennitalaError::InvalidMonth),
+            ("311100", KennitalaError::InvalidDay),
+            ("001000", KennitalaError::InvalidDay),
+            ("400100", KennitalaError::InvalidDay),
+            ("290201", KennitalaError::InvalidDay),
+        ];
+        for (prefix, error) in &cases {
+            assert_eq!(DatePrefix::parse(prefix), Err(*error), "{}", prefix);
+        }
+    }
+
+    #[test]
+    fn leap_day_depends_on_century() {
+        // 1900 was not a leap year, but 2000 was.
+        let date = DatePrefix::parse("290200").unwrap();
+        let kt = Kennitala::new_with_cached_date(&date, "2020").unwrap();
+        assert_eq!(kt.get_year(), 2000);
+        assert_eq!(
+            Kennitala::new_with_cached_date(&date, "2029"),
+            Err(KennitalaError::InvalidDay)
+        );
+    }
+}
@@ -14,15 +14,55 @@ const DAYS_IN_MONTH: [u8; 12] = [
 ];
 
 #[inline]
-fn is_leap_year(year: u32) -> bool {
-    (year % 4 == 0) && (year % 100 != 0 || year % 400 == 0)
+const fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
 }
 
+/// Get the number of days in the given month of the given year, taking leap
+/// years into account. Months are numbered from 1 to 12, and `None` is returned
+/// for any other month.
+///
+/// ```
+/// use kennitolur::days_in_month;
+///
+/// assert_eq!(days_in_month(2, 2000), Some(29));
+/// assert_eq!(days_in_month(2, 1900), Some(28));
+/// assert_eq!(days_in_month(13, 2000), None);
+/// ```
 #[inline]
-pub fn days_in_month(month: u32, year: u32) -> u32 {
-    if (month == 2) && is_leap_year(year) {
-        29
+pub const fn days_in_month(month: u32, year: u32) -> Option<u8> {
+    if month == 0 || month > 12 {
+        None
+    } else if (month == 2) && is_leap_year(year) {
+        Some(29)
     } else {
-        DAYS_IN_MONTH[(month - 1) as usize] as u32
+        Some(DAYS_IN_MONTH[(month - 1) as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_range_months() {
+        assert_eq!(days_in_month(0, 2000), None);
+        assert_eq!(days_in_month(13, 2000), None);
+        assert_eq!(days_in_month(u32::MAX, 2000), None);
+    }
+
+    #[test]
+    fn first_and_last_months() {
+        assert_eq!(days_in_month(1, 1999), Some(31));
+        assert_eq!(days_in_month(12, 1999), Some(31));
+    }
+
+    #[test]
+    fn february() {
+        assert_eq!(days_in_month(2, 2000), Some(29));
+        assert_eq!(days_in_month(2, 2004), Some(29));
+        assert_eq!(days_in_month(2, 2001), Some(28));
+        assert_eq!(days_in_month(2, 1900), Some(28));
+        assert_eq!(days_in_month(2, 1800), Some(28));
     }
 }
@@ -1,13 +1,25 @@
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
 
 /// Errors which can come up when validating a given kennitala.
-#[derive(Debug, Copy, Clone)]
+///
+/// New variants may be added in minor releases, so matches on this enum
+/// outside of this crate need a wildcard arm.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum KennitalaError {
     /// The kennitala given does not have 10 digits.
     InvalidLength(usize),
-    /// The string given cannot be coverted into a valid `u32` number.
-    InvalidNumber,
+    /// The kennitala given contains something which is not a digit.
+    InvalidNumber {
+        /// The position of the offending character. For strings and bytes,
+        /// this is a byte offset into the input.
+        index: usize,
+        /// The offending character. Bytes which are not ASCII, and integers
+        /// which are not digits, are reported as `char::REPLACEMENT_CHARACTER`.
+        found: char,
+    },
     /// The 1st and 2nd digits representing the day of birth are invalid for
     /// the given month and year.
     InvalidDay,
@@ -19,27 +31,352 @@ pub enum KennitalaError {
     /// The 9th digit -- containing the checksum for this kennital --is
     /// invalid.
     InvalidChecksum,
-    /// The 10th digit -- representing the century of birth -- is not `9` or
-    /// `0`. This means that the person was born in the future!
+    /// The 10th digit -- representing the century of birth -- is not `8`, `9`
+    /// or `0`. This means that the person was born in the future!
     InvalidCentury,
+    /// The date of birth given is outside of the years 1800 to 2099, which
+    /// are the only ones a kennitala can represent. This is returned by the
+    /// constructors which take a date, `Kennitala::from_parts` and
+    /// `Kennitala::generate_random`, as a parsed kennitala always has a year
+    /// in this range.
+    DateOutOfRange,
+    /// The kennitala given is a placeholder, with all 10 digits the same, such
+    /// as `0000000000`. These are used by some systems in place of a missing
+    /// kennitala, and are never valid.
+    Placeholder,
+    /// The value given is not the packed representation of a kennitala, as
+    /// returned by `Kennitala::as_u32`, even though its fields may be. This is
+    /// returned by `validate_raw`.
+    InvalidRepresentation,
 }
 
 impl fmt::Display for KennitalaError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             KennitalaError::InvalidLength(n) => write!(f, "Length {} is invalid", n),
-            KennitalaError::InvalidNumber => write!(f, "Invalid number"),
+            KennitalaError::InvalidNumber { index, found } => {
+                write!(f, "Invalid character {:?} at position {}", found, index)
+            }
             KennitalaError::InvalidDay => write!(f, "Day of birth is invalid"),
             KennitalaError::InvalidMonth => write!(f, "Month of birth is invalid"),
             KennitalaError::InvalidRandomDigits => write!(f, "The random digits are invalid"),
             KennitalaError::InvalidChecksum => write!(f, "The kennitala's checksum is invalid"),
             KennitalaError::InvalidCentury => write!(f, "Century of birth is invalid"),
+            KennitalaError::DateOutOfRange => {
+                write!(f, "Date of birth is not between the years 1800 and 2099")
+            }
+            KennitalaError::Placeholder => write!(f, "The kennitala is a placeholder"),
+            KennitalaError::InvalidRepresentation => {
+                write!(f, "The packed representation of the kennitala is invalid")
+            }
         }
     }
 }
 
+impl KennitalaError {
+    /// The number of variants.
+    const VARIANTS: usize = 10;
+
+    /// The names of the variants, in the order given by `variant_index`.
+    const VARIANT_NAMES: [&'static str; KennitalaError::VARIANTS] = [
+        "InvalidLength",
+        "InvalidNumber",
+        "InvalidDay",
+        "InvalidMonth",
+        "InvalidRandomDigits",
+        "InvalidChecksum",
+        "InvalidCentury",
+        "DateOutOfRange",
+        "Placeholder",
+        "InvalidRepresentation",
+    ];
+
+    /// The codes of the variants, in the order given by `variant_index`.
+    const CODES: [&'static str; KennitalaError::VARIANTS] = [
+        "invalid_length",
+        "invalid_number",
+        "invalid_day",
+        "invalid_month",
+        "invalid_random_digits",
+        "invalid_checksum",
+        "invalid_century",
+        "date_out_of_range",
+        "placeholder",
+        "invalid_representation",
+    ];
+
+    /// The Icelandic messages of the variants, in the order given by
+    /// `variant_index`.
+    const MESSAGES_IS: [&'static str; KennitalaError::VARIANTS] = [
+        "Ógild lengd",
+        "Ógilt tákn",
+        "Ógildur dagur",
+        "Ógildur mánuður",
+        "Ógildir slembitölustafir",
+        "Röng vartala",
+        "Ógild öld",
+        "Dagsetning utan leyfilegs bils",
+        "Gervikennitala",
+        "Ógild framsetning",
+    ];
+
+    /// Get a short message in Icelandic describing this error's variant, for
+    /// user interfaces in Icelandic. Unlike the `Display` output, it does not
+    /// include the contents of the error, such as the length in
+    /// `KennitalaError::InvalidLength`. The messages are:
+    ///
+    /// | Variant                 | Message                            |
+    /// |-------------------------|------------------------------------|
+    /// | `InvalidLength`         | `"Ógild lengd"`                    |
+    /// | `InvalidNumber`         | `"Ógilt tákn"`                     |
+    /// | `InvalidDay`            | `"Ógildur dagur"`                  |
+    /// | `InvalidMonth`          | `"Ógildur mánuður"`                |
+    /// | `InvalidRandomDigits`   | `"Ógildir slembitölustafir"`       |
+    /// | `InvalidChecksum`       | `"Röng vartala"`                   |
+    /// | `InvalidCentury`        | `"Ógild öld"`                      |
+    /// | `DateOutOfRange`        | `"Dagsetning utan leyfilegs bils"` |
+    /// | `Placeholder`           | `"Gervikennitala"`                 |
+    /// | `InvalidRepresentation` | `"Ógild framsetning"`              |
+    pub fn message_is(&self) -> &'static str {
+        KennitalaError::MESSAGES_IS[self.variant_index()]
+    }
+
+    /// Get a stable, machine-readable code for this error's variant, for
+    /// example to look up a translated message. Unlike the `Display` output,
+    /// the codes will not change between releases. They are:
+    ///
+    /// | Variant                 | Code                       |
+    /// |-------------------------|----------------------------|
+    /// | `InvalidLength`         | `"invalid_length"`         |
+    /// | `InvalidNumber`         | `"invalid_number"`         |
+    /// | `InvalidDay`            | `"invalid_day"`            |
+    /// | `InvalidMonth`          | `"invalid_month"`          |
+    /// | `InvalidRandomDigits`   | `"invalid_random_digits"`  |
+    /// | `InvalidChecksum`       | `"invalid_checksum"`       |
+    /// | `InvalidCentury`        | `"invalid_century"`        |
+    /// | `DateOutOfRange`        | `"date_out_of_range"`      |
+    /// | `Placeholder`           | `"placeholder"`            |
+    /// | `InvalidRepresentation` | `"invalid_representation"` |
+    pub fn code(&self) -> &'static str {
+        KennitalaError::CODES[self.variant_index()]
+    }
+
+    /// Get the name of this error's variant, such as `"InvalidChecksum"`.
+    /// This is the name the `actix-web`, `validator` and `wasm` integrations
+    /// report errors by.
+    ///
+    /// ```
+    /// use kennitolur::{Kennitala, KennitalaError};
+    ///
+    /// let error = Kennitala::new("3110002930").unwrap_err();
+    /// assert_eq!(error.variant_name(), "InvalidChecksum");
+    /// ```
+    pub fn variant_name(&self) -> &'static str {
+        KennitalaError::VARIANT_NAMES[self.variant_index()]
+    }
+
+    /// Get the index of this error's variant, ignoring its contents.
+    fn variant_index(&self) -> usize {
+        match self {
+            KennitalaError::InvalidLength(_) => 0,
+            KennitalaError::InvalidNumber { .. } => 1,
+            KennitalaError::InvalidDay => 2,
+            KennitalaError::InvalidMonth => 3,
+            KennitalaError::InvalidRandomDigits => 4,
+            KennitalaError::InvalidChecksum => 5,
+            KennitalaError::InvalidCentury => 6,
+            KennitalaError::DateOutOfRange => 7,
+            KennitalaError::Placeholder => 8,
+            KennitalaError::InvalidRepresentation => 9,
+        }
+    }
+
+    /// Replace the character of an `InvalidNumber` error, whose index is
+    /// relative to the part of `kennitala` starting at `offset`, with the
+    /// whole character found in `kennitala`.
+    pub(crate) fn locate_in(self, kennitala: &str, offset: usize) -> Self {
+        match self {
+            KennitalaError::InvalidNumber { index, found } => {
+                let index = offset + index;
+                let found = kennitala
+                    .get(index..)
+                    .and_then(|rest| rest.chars().next())
+                    .unwrap_or(found);
+                KennitalaError::InvalidNumber { index, found }
+            }
+            e => e,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 impl Error for KennitalaError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        None
+        // None of the variants has an underlying cause yet. One which does
+        // should hold the cause by value and return it here, rather than boxing
+        // it, so that the error stays `Copy` and can be created in `const fn`.
+        match self {
+            KennitalaError::InvalidLength(_)
+            | KennitalaError::InvalidNumber { .. }
+            | KennitalaError::InvalidDay
+            | KennitalaError::InvalidMonth
+            | KennitalaError::InvalidRandomDigits
+            | KennitalaError::InvalidChecksum
+            | KennitalaError::InvalidCentury
+            | KennitalaError::DateOutOfRange
+            | KennitalaError::Placeholder
+            | KennitalaError::InvalidRepresentation => None,
+        }
+    }
+}
+
+/// The number of errors of each `KennitalaError` variant, for reporting on
+/// bulk validation. The contents of the errors, such as the length in
+/// `KennitalaError::InvalidLength`, are not distinguished.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ErrorCounts {
+    counts: [u64; KennitalaError::VARIANTS],
+}
+
+impl ErrorCounts {
+    /// Count the given error.
+    pub fn add(&mut self, error: &KennitalaError) {
+        self.counts[error.variant_index()] += 1;
+    }
+
+    /// Get the number of errors of the same variant as the given error.
+    pub fn get(&self, error: &KennitalaError) -> u64 {
+        self.counts[error.variant_index()]
+    }
+
+    /// Get the total number of errors.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Iterate over the names of the variants which were counted at least once,
+    /// such as `"InvalidChecksum"`, along with their counts.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> + '_ {
+        KennitalaError::VARIANT_NAMES
+            .iter()
+            .zip(self.counts.iter())
+            .filter(|(_, count)| **count > 0)
+            .map(|(name, count)| (*name, *count))
+    }
+}
+
+/// The message with which the integrations reject a kennitala given as a
+/// negative integer, so that they all word it the same.
+#[cfg(any(feature = "async-graphql", feature = "redis", feature = "serde"))]
+pub(crate) struct NegativeKennitala(pub(crate) i64);
+
+#[cfg(any(feature = "async-graphql", feature = "redis", feature = "serde"))]
+impl fmt::Display for NegativeKennitala {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Kennitala can not be negative, found {}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_counts() {
+        let mut counts = ErrorCounts::default();
+        counts.add(&KennitalaError::InvalidLength(3));
+        counts.add(&KennitalaError::InvalidLength(11));
+        counts.add(&KennitalaError::InvalidChecksum);
+        assert_eq!(counts.get(&KennitalaError::InvalidLength(0)), 2);
+        assert_eq!(counts.get(&KennitalaError::InvalidChecksum), 1);
+        assert_eq!(counts.get(&KennitalaError::InvalidDay), 0);
+        assert_eq!(counts.total(), 3);
+        let counted: Vec<_> = counts.iter().collect();
+        assert_eq!(counted, [("InvalidLength", 2), ("InvalidChecksum", 1)]);
+    }
+
+    #[test]
+    fn codes() {
+        let cases = [
+            (KennitalaError::InvalidLength(9), "invalid_length"),
+            (
+                KennitalaError::InvalidNumber {
+                    index: 3,
+                    found: 'x',
+                },
+                "invalid_number",
+            ),
+            (KennitalaError::InvalidDay, "invalid_day"),
+            (KennitalaError::InvalidMonth, "invalid_month"),
+            (KennitalaError::InvalidRandomDigits, "invalid_random_digits"),
+            (KennitalaError::InvalidChecksum, "invalid_checksum"),
+            (KennitalaError::InvalidCentury, "invalid_century"),
+            (KennitalaError::DateOutOfRange, "date_out_of_range"),
+            (KennitalaError::Placeholder, "placeholder"),
+            (
+                KennitalaError::InvalidRepresentation,
+                "invalid_representation",
+            ),
+        ];
+        assert_eq!(cases.len(), KennitalaError::VARIANTS);
+        for (error, code) in &cases {
+            assert_eq!(error.code(), *code);
+        }
+    }
+
+    #[test]
+    fn icelandic_messages() {
+        let cases = [
+            (KennitalaError::InvalidLength(9), "Ógild lengd"),
+            (
+                KennitalaError::InvalidNumber {
+                    index: 3,
+                    found: 'x',
+                },
+                "Ógilt tákn",
+            ),
+            (KennitalaError::InvalidChecksum, "Röng vartala"),
+            (KennitalaError::Placeholder, "Gervikennitala"),
+        ];
+        for (error, message) in &cases {
+            assert_eq!(error.message_is(), *message);
+        }
+        for (i, message) in KennitalaError::MESSAGES_IS.iter().enumerate() {
+            assert!(!message.is_empty());
+            assert!(!KennitalaError::MESSAGES_IS[..i].contains(message));
+        }
+    }
+
+    /// One error of each variant, in the order given by `variant_index`.
+    const ERRORS: [KennitalaError; KennitalaError::VARIANTS] = [
+        KennitalaError::InvalidLength(0),
+        KennitalaError::InvalidNumber {
+            index: 0,
+            found: 'a',
+        },
+        KennitalaError::InvalidDay,
+        KennitalaError::InvalidMonth,
+        KennitalaError::InvalidRandomDigits,
+        KennitalaError::InvalidChecksum,
+        KennitalaError::InvalidCentury,
+        KennitalaError::DateOutOfRange,
+        KennitalaError::Placeholder,
+        KennitalaError::InvalidRepresentation,
+    ];
+
+    #[test]
+    fn variant_names() {
+        for (i, error) in ERRORS.iter().enumerate() {
+            assert_eq!(error.variant_index(), i);
+            assert!(format!("{:?}", error).starts_with(error.variant_name()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn no_source() {
+        for error in &ERRORS {
+            assert!(error.source().is_none());
+        }
     }
 }
@@ -0,0 +1,227 @@
+//! A C interface, for validating kennitölur from other languages. Build the
+//! crate as a `staticlib` or `cdylib` with the `ffi` feature, and include
+//! `include/kennitolur.h`, which is generated from this module with
+//! `cbindgen --config cbindgen.toml --output include/kennitolur.h`.
+//!
+//! ```c
+//! #include "kennitolur.h"
+//!
+//! uint32_t packed;
+//! char formatted[11];
+//! if (kennitolur_parse("3110002920", 10, &packed) == KENNITOLUR_OK) {
+//!     kennitolur_format(packed, formatted);
+//! }
+//! ```
+//!
+//! Functions return `KENNITOLUR_OK` on success, or one of the negative
+//! `KENNITOLUR_*` codes, one for each `KennitalaError` variant.
+// Exporting functions with `#[no_mangle]` and reading from the pointers passed
+// by C callers is unsafe.
+#![allow(unsafe_code)]
+
+use core::ffi::c_char;
+use core::slice;
+
+use crate::{validate_raw, Kennitala, KennitalaError};
+
+/// Success.
+pub const KENNITOLUR_OK: i32 = 0;
+/// `KennitalaError::InvalidLength`.
+pub const KENNITOLUR_INVALID_LENGTH: i32 = -1;
+/// `KennitalaError::InvalidNumber`.
+pub const KENNITOLUR_INVALID_NUMBER: i32 = -2;
+/// `KennitalaError::InvalidDay`.
+pub const KENNITOLUR_INVALID_DAY: i32 = -3;
+/// `KennitalaError::InvalidMonth`.
+pub const KENNITOLUR_INVALID_MONTH: i32 = -4;
+/// `KennitalaError::InvalidRandomDigits`.
+pub const KENNITOLUR_INVALID_RANDOM_DIGITS: i32 = -5;
+/// `KennitalaError::InvalidChecksum`.
+pub const KENNITOLUR_INVALID_CHECKSUM: i32 = -6;
+/// `KennitalaError::InvalidCentury`.
+pub const KENNITOLUR_INVALID_CENTURY: i32 = -7;
+/// `KennitalaError::DateOutOfRange`.
+pub const KENNITOLUR_DATE_OUT_OF_RANGE: i32 = -8;
+/// `KennitalaError::Placeholder`.
+pub const KENNITOLUR_PLACEHOLDER: i32 = -9;
+/// `KennitalaError::InvalidRepresentation`.
+pub const KENNITOLUR_INVALID_REPRESENTATION: i32 = -10;
+/// A pointer argument was null. This is far from the codes of the
+/// `KennitalaError` variants, so that new variants don't collide with it.
+pub const KENNITOLUR_NULL_POINTER: i32 = -100;
+
+/// Get the code returned for the given error.
+fn error_code(error: &KennitalaError) -> i32 {
+    match error {
+        KennitalaError::InvalidLength(_) => KENNITOLUR_INVALID_LENGTH,
+        KennitalaError::InvalidNumber { .. } => KENNITOLUR_INVALID_NUMBER,
+        KennitalaError::InvalidDay => KENNITOLUR_INVALID_DAY,
+        KennitalaError::InvalidMonth => KENNITOLUR_INVALID_MONTH,
+        KennitalaError::InvalidRandomDigits => KENNITOLUR_INVALID_RANDOM_DIGITS,
+        KennitalaError::InvalidChecksum => KENNITOLUR_INVALID_CHECKSUM,
+        KennitalaError::InvalidCentury => KENNITOLUR_INVALID_CENTURY,
+        KennitalaError::DateOutOfRange => KENNITOLUR_DATE_OUT_OF_RANGE,
+        KennitalaError::Placeholder => KENNITOLUR_PLACEHOLDER,
+        KennitalaError::InvalidRepresentation => KENNITOLUR_INVALID_REPRESENTATION,
+    }
+}
+
+/// Parse the `len` bytes at `kennitala`, which need not be null-terminated.
+unsafe fn parse(kennitala: *const c_char, len: usize) -> Result<Kennitala, i32> {
+    if kennitala.is_null() {
+        return Err(KENNITOLUR_NULL_POINTER);
+    }
+    let bytes = slice::from_raw_parts(kennitala.cast::<u8>(), len);
+    Kennitala::from_bytes(bytes).map_err(|e| error_code(&e))
+}
+
+/// Check whether the `len` bytes at `kennitala` are a valid kennitala, as with
+/// `Kennitala::from_bytes`. The string need not be null-terminated. Returns
+/// `KENNITOLUR_OK` if it is valid, and the code of the error otherwise.
+///
+/// # Safety
+///
+/// `kennitala` must be null, or point to at least `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn kennitolur_validate(kennitala: *const c_char, len: usize) -> i32 {
+    match parse(kennitala, len) {
+        Ok(_) => KENNITOLUR_OK,
+        Err(code) => code,
+    }
+}
+
+/// Parse the `len` bytes at `kennitala`, as with `kennitolur_validate`, and
+/// on success write its packed representation, as with `Kennitala::as_u32`,
+/// to `out`. Nothing is written on failure.
+///
+/// # Safety
+///
+/// `kennitala` must be null, or point to at least `len` readable bytes, and
+/// `out` must be null or valid for writing a `uint32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn kennitolur_parse(
+    kennitala: *const c_char,
+    len: usize,
+    out: *mut u32,
+) -> i32 {
+    if out.is_null() {
+        return KENNITOLUR_NULL_POINTER;
+    }
+    match parse(kennitala, len) {
+        Ok(kennitala) => {
+            *out = kennitala.as_u32();
+            KENNITOLUR_OK
+        }
+        Err(code) => code,
+    }
+}
+
+/// Write the 10 digits of the kennitala with the given packed representation,
+/// as returned by `kennitolur_parse`, followed by a null terminator, to `out`.
+/// The packed representation is validated as with `validate_raw`, and nothing
+/// is written if it is invalid.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing 11 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn kennitolur_format(packed: u32, out: *mut c_char) -> i32 {
+    if out.is_null() {
+        return KENNITOLUR_NULL_POINTER;
+    }
+    let kennitala = match validate_raw(packed) {
+        Ok(kennitala) => kennitala,
+        Err(e) => return error_code(&e),
+    };
+    let out = slice::from_raw_parts_mut(out.cast::<u8>(), 11);
+    for (byte, digit) in out.iter_mut().zip(kennitala.digits().iter()) {
+        *byte = b'0' + digit;
+    }
+    out[10] = 0;
+    KENNITOLUR_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    fn validate(kennitala: &str) -> i32 {
+        unsafe { kennitolur_validate(kennitala.as_ptr().cast(), kennitala.len()) }
+    }
+
+    #[test]
+    fn validate_codes() {
+        let cases = [
+            ("3110002920", KENNITOLUR_OK),
+            ("7112993009", KENNITOLUR_OK),
+            ("311000", KENNITOLUR_INVALID_LENGTH),
+            ("31100O2920", KENNITOLUR_INVALID_NUMBER),
+            ("3210002070", KENNITOLUR_INVALID_DAY),
+            ("3113002020", KENNITOLUR_INVALID_MONTH),
+            ("3110001010", KENNITOLUR_INVALID_RANDOM_DIGITS),
+            ("3110002930", KENNITOLUR_INVALID_CHECKSUM),
+            ("3110002921", KENNITOLUR_INVALID_CENTURY),
+            ("1111111111", KENNITOLUR_PLACEHOLDER),
+        ];
+        for (input, code) in &cases {
+            assert_eq!(validate(input), *code, "{}", input);
+        }
+        // Only the given length is read.
+        assert_eq!(validate("3110002920xyz".get(..10).unwrap()), KENNITOLUR_OK);
+    }
+
+    #[test]
+    fn parse_and_format() {
+        let mut packed = 0;
+        let kennitala = "1703715939";
+        let code = unsafe { kennitolur_parse(kennitala.as_ptr().cast(), 10, &mut packed) };
+        assert_eq!(code, KENNITOLUR_OK);
+        assert_eq!(packed, Kennitala::new(kennitala).unwrap().as_u32());
+
+        let mut out: [c_char; 11] = [0x7f; 11];
+        assert_eq!(
+            unsafe { kennitolur_format(packed, out.as_mut_ptr()) },
+            KENNITOLUR_OK
+        );
+        let out: Vec<u8> = out.iter().map(|c| *c as u8).collect();
+        assert_eq!(out, b"1703715939\0");
+    }
+
+    #[test]
+    fn failures_write_nothing() {
+        let mut packed = 42;
+        let kennitala = "1703715949";
+        let code = unsafe { kennitolur_parse(kennitala.as_ptr().cast(), 10, &mut packed) };
+        assert_eq!(code, KENNITOLUR_INVALID_CHECKSUM);
+        assert_eq!(packed, 42);
+
+        let mut out: [c_char; 11] = [0x7f; 11];
+        let code = unsafe { kennitolur_format(0x4524_015f, out.as_mut_ptr()) };
+        assert_eq!(code, KENNITOLUR_INVALID_REPRESENTATION);
+        assert_eq!(out, [0x7f; 11]);
+    }
+
+    #[test]
+    fn null_pointers() {
+        let mut packed = 0;
+        unsafe {
+            assert_eq!(
+                kennitolur_validate(ptr::null(), 10),
+                KENNITOLUR_NULL_POINTER
+            );
+            assert_eq!(
+                kennitolur_parse(ptr::null(), 10, &mut packed),
+                KENNITOLUR_NULL_POINTER
+            );
+            assert_eq!(
+                kennitolur_parse(b"3110002920".as_ptr().cast(), 10, ptr::null_mut()),
+                KENNITOLUR_NULL_POINTER
+            );
+            assert_eq!(
+                kennitolur_format(packed, ptr::null_mut()),
+                KENNITOLUR_NULL_POINTER
+            );
+        }
+    }
+}
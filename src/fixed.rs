@@ -0,0 +1,94 @@
+use core::fmt;
+use core::ops::Deref;
+use core::str;
+
+use crate::Kennitala;
+
+/// The canonical 10 digit string of a kennitala, stored inline rather than on
+/// the heap. It is created with `Kennitala::to_fixed`, and dereferences to
+/// `str`.
+///
+/// ```
+/// use kennitolur::Kennitala;
+///
+/// let kennitala = Kennitala::new("3110002920").unwrap();
+/// let fixed = kennitala.to_fixed();
+/// assert_eq!(&*fixed, "3110002920");
+/// assert!(fixed.starts_with("311000"));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct FixedKennitala {
+    bytes: [u8; 10],
+}
+
+impl FixedKennitala {
+    /// Get the kennitala as a string slice.
+    pub fn as_str(&self) -> &str {
+        // The bytes are always ASCII digits.
+        str::from_utf8(&self.bytes).expect("kennitala digits are ASCII")
+    }
+}
+
+impl Deref for FixedKennitala {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for FixedKennitala {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for FixedKennitala {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Debug for FixedKennitala {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for FixedKennitala {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Kennitala {
+    /// Get the canonical 10 digit string of this kennitala, the same as the
+    /// `Display` output, without allocating.
+    pub fn to_fixed(&self) -> FixedKennitala {
+        let mut bytes = [0; 10];
+        for (byte, digit) in bytes.iter_mut().zip(&self.digits()) {
+            *byte = b'0' + digit;
+        }
+        FixedKennitala { bytes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn agrees_with_display() {
+        for kt in &["3110002920", "0311203149", "1703715939", "7112993009"] {
+            let kt = Kennitala::new(kt).unwrap();
+            let fixed = kt.to_fixed();
+            let as_ref: &str = fixed.as_ref();
+            assert_eq!(as_ref, kt.to_string());
+            assert_eq!(&*fixed, kt.to_string());
+            assert_eq!(fixed.to_string(), kt.to_string());
+            assert_eq!(format!("{:?}", fixed), format!("{:?}", kt.to_string()));
+            assert_eq!(Kennitala::new(&fixed), Ok(kt));
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Validation of kennitölur in structs deriving `garde::Validate`. The
+//! functions work with any context. Wrap them in `inner` for `Option` fields,
+//! which are then only validated when they are `Some`:
+//!
+//! ```
+//! use garde::Validate;
+//!
+//! #[derive(Validate)]
+//! struct NewCompany {
+//!     #[garde(custom(kennitolur::garde::is_company_kennitala))]
+//!     kennitala: String,
+//!     #[garde(inner(custom(kennitolur::garde::is_kennitala)))]
+//!     contact: Option<String>,
+//! }
+//!
+//! let company = NewCompany {
+//!     kennitala: "7112993009".to_string(),
+//!     contact: Some("3110002930".to_string()),
+//! };
+//! let report = company.validate().unwrap_err();
+//! assert_eq!(
+//!     report.to_string(),
+//!     "contact: The kennitala's checksum is invalid\n"
+//! );
+//! ```
+use garde::{Error, Result};
+
+use crate::Kennitala;
+
+/// Check whether the given string is a valid kennitala, as with `validate`,
+/// for use with `#[garde(custom(kennitolur::garde::is_kennitala))]`. The
+/// error message is that of the `KennitalaError`.
+pub fn is_kennitala<C>(value: &str, _context: &C) -> Result {
+    crate::validate(value).map_err(|e| Error::new(e.to_string()))
+}
+
+/// Check whether the given string is a valid kennitala belonging to a
+/// company, for use with
+/// `#[garde(custom(kennitolur::garde::is_company_kennitala))]`. A valid
+/// kennitala of an individual is rejected with the message "The kennitala
+/// does not belong to a company".
+pub fn is_company_kennitala<C>(value: &str, _context: &C) -> Result {
+    let kennitala = Kennitala::new(value).map_err(|e| Error::new(e.to_string()))?;
+    if kennitala.is_company() {
+        Ok(())
+    } else {
+        Err(Error::new("The kennitala does not belong to a company"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use garde::Validate;
+
+    #[derive(Validate)]
+    struct Person {
+        #[garde(length(min = 1))]
+        name: String,
+        #[garde(custom(is_kennitala))]
+        kennitala: String,
+        #[garde(inner(custom(is_kennitala)))]
+        spouse: Option<String>,
+    }
+
+    #[derive(Validate)]
+    struct Company {
+        #[garde(custom(is_company_kennitala))]
+        kennitala: String,
+        #[garde(dive)]
+        owner: Person,
+        #[garde(dive)]
+        board: Vec<Person>,
+    }
+
+    fn person(kennitala: &str, spouse: Option<&str>) -> Person {
+        Person {
+            name: "Jón".to_string(),
+            kennitala: kennitala.to_string(),
+            spouse: spouse.map(str::to_string),
+        }
+    }
+
+    // The errors sorted by path, since the order of the report is unspecified.
+    fn errors<T: Validate<Context = ()>>(value: &T) -> Vec<(String, String)> {
+        let mut errors: Vec<_> = match value.validate() {
+            Ok(()) => Vec::new(),
+            Err(report) => report
+                .iter()
+                .map(|(path, error)| (path.to_string(), error.message().to_string()))
+                .collect(),
+        };
+        errors.sort();
+        errors
+    }
+
+    fn error(path: &str, message: &str) -> (String, String) {
+        (path.to_string(), message.to_string())
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(is_kennitala("3110002920", &()), Ok(()));
+        assert_eq!(is_company_kennitala("7112993009", &()), Ok(()));
+        assert_eq!(errors(&person("3110002920", None)), []);
+        assert_eq!(errors(&person("3110002920", Some("1703715939"))), []);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            is_kennitala("3110002930", &()).unwrap_err().message(),
+            "The kennitala's checksum is invalid"
+        );
+        assert_eq!(
+            is_company_kennitala("3110002920", &())
+                .unwrap_err()
+                .message(),
+            "The kennitala does not belong to a company"
+        );
+        assert_eq!(
+            is_company_kennitala("7112993019", &())
+                .unwrap_err()
+                .message(),
+            "The kennitala's checksum is invalid"
+        );
+    }
+
+    #[test]
+    fn option_field() {
+        assert_eq!(
+            errors(&person("3110002920", Some("3110002921"))),
+            [error("spouse", "Century of birth is invalid")]
+        );
+        assert_eq!(
+            errors(&person("311000", Some("31100O2920"))),
+            [
+                error("kennitala", "Length 6 is invalid"),
+                error("spouse", "Invalid character 'O' at position 5"),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let company = Company {
+            kennitala: "3110002920".to_string(),
+            owner: person("1703715949", Some("0311203149")),
+            board: vec![
+                person("0311203149", None),
+                person("0101502998", Some("3110002930")),
+            ],
+        };
+        assert_eq!(
+            errors(&company),
+            [
+                error("board[1].spouse", "The kennitala's checksum is invalid"),
+                error("kennitala", "The kennitala does not belong to a company"),
+                error("owner.kennitala", "The kennitala's checksum is invalid"),
+            ]
+        );
+
+        let company = Company {
+            kennitala: "7112993009".to_string(),
+            owner: person("3110002920", None),
+            board: Vec::new(),
+        };
+        assert_eq!(errors(&company), []);
+    }
+}
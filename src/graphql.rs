@@ -0,0 +1,34 @@
+//! A GraphQL scalar for kennitölur through `async-graphql`. A kennitala is
+//! output as its canonical 10 digit string, and can be input either as such a
+//! string or as an integer.
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use crate::error::NegativeKennitala;
+use crate::Kennitala;
+
+/// A kennitala, as its canonical 10 digit string.
+#[Scalar(name = "Kennitala")]
+impl ScalarType for Kennitala {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(kennitala) => Ok(Kennitala::new(kennitala)?),
+            Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+                (Some(kennitala), _) => Ok(Kennitala::from_u64(kennitala)?),
+                (None, Some(negative)) => Err(InputValueError::custom(NegativeKennitala(negative))),
+                (None, None) => Err(InputValueError::custom(format!(
+                    "a kennitala must be an integer, found {}",
+                    number
+                ))),
+            },
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_) | Value::Number(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
@@ -0,0 +1,176 @@
+//! Formatting of a kennitala as it is being typed into a form.
+//!
+//! ```
+//! use kennitolur::input::format_as_you_type;
+//!
+//! let typed = format_as_you_type("31100029");
+//! assert_eq!(typed.display(), "311000-29");
+//! // Only one checksum digit is valid after the first 8 digits.
+//! assert_eq!(typed.next().iter().collect::<Vec<_>>(), [2]);
+//! ```
+use crate::{DigitSet, Kennitala, KennitalaError, PrefixStatus};
+
+/// A kennitala as it is being typed, as returned by `format_as_you_type`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AsYouType {
+    display: String,
+    status: Result<PrefixStatus, KennitalaError>,
+}
+
+impl AsYouType {
+    /// Get the digits typed so far, with a hyphen between the sixth and
+    /// seventh digit once there is a seventh digit, as in `311000-29`. If
+    /// something other than a digit was typed, this is the input as given.
+    pub fn display(&self) -> &str {
+        &self.display
+    }
+
+    /// Get the digits which can be typed next, such that the kennitala can
+    /// still become valid. This is empty once the kennitala is complete, and
+    /// if it cannot become valid.
+    pub fn next(&self) -> DigitSet {
+        match self.status {
+            Ok(PrefixStatus::Incomplete { next }) => next,
+            _ => DigitSet::default(),
+        }
+    }
+
+    /// Get the kennitala, if all of its digits have been typed and it is
+    /// valid.
+    pub fn kennitala(&self) -> Option<Kennitala> {
+        match self.status {
+            Ok(PrefixStatus::Complete(kt)) => Some(kt),
+            _ => None,
+        }
+    }
+
+    /// Get the reason why what has been typed cannot become a valid
+    /// kennitala, if it cannot.
+    pub fn error(&self) -> Option<KennitalaError> {
+        self.status.err()
+    }
+}
+
+/// Format the digits of a kennitala typed so far for display, and find which
+/// digits can be typed next, as checked by `Kennitala::validate_prefix`. The
+/// input may contain a hyphen between the sixth and seventh digit, so that the
+/// display string can be passed back in after the next keystroke.
+pub fn format_as_you_type(typed: &str) -> AsYouType {
+    let digits: String = typed
+        .char_indices()
+        .filter(|(index, c)| !(*c == '-' && *index == 6))
+        .map(|(_, c)| c)
+        .collect();
+    let mut status = Kennitala::validate_prefix(&digits);
+    // The position of an invalid character is in the input as typed, as is
+    // the display string in that case, not in the digits without the hyphen.
+    if let Err(KennitalaError::InvalidNumber { index, .. }) = &mut status {
+        if *index >= 6 && digits.len() < typed.len() {
+            *index += 1;
+        }
+    }
+    let display = match status {
+        Err(KennitalaError::InvalidNumber { .. }) => typed.to_string(),
+        _ if digits.len() > 6 => format!("{}-{}", &digits[..6], &digits[6..]),
+        _ => digits,
+    };
+    AsYouType { display, status }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next(typed: &str) -> Vec<u8> {
+        format_as_you_type(typed).next().iter().collect()
+    }
+
+    #[test]
+    fn every_position() {
+        let table: [(&str, &str, &[u8]); 10] = [
+            ("", "", &[0, 1, 2, 3, 4, 5, 6, 7]),
+            ("3", "3", &[0, 1]),
+            ("31", "31", &[0, 1]),
+            ("311", "311", &[0, 2]),
+            ("3110", "3110", &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            ("31100", "31100", &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            ("311000", "311000", &[2, 3, 4, 5, 6, 7, 8, 9]),
+            ("3110002", "311000-2", &[0, 1, 2, 3, 4, 6, 7, 8, 9]),
+            ("31100029", "311000-29", &[2]),
+            ("311000292", "311000-292", &[0, 8, 9]),
+        ];
+        for (typed, display, digits) in &table {
+            let formatted = format_as_you_type(typed);
+            assert_eq!(formatted.display(), *display, "{:?}", typed);
+            assert_eq!(next(typed), *digits, "{:?}", typed);
+            assert_eq!(formatted.kennitala(), None);
+            assert_eq!(formatted.error(), None);
+        }
+    }
+
+    #[test]
+    fn complete() {
+        let formatted = format_as_you_type("3110002920");
+        assert_eq!(formatted.display(), "311000-2920");
+        assert!(formatted.next().is_empty());
+        assert_eq!(
+            formatted.kennitala(),
+            Some(Kennitala::new("3110002920").unwrap())
+        );
+    }
+
+    #[test]
+    fn display_passed_back_in() {
+        let formatted = format_as_you_type("311000-29");
+        assert_eq!(formatted.display(), "311000-29");
+        assert_eq!(next("311000-29"), [2]);
+        let formatted = format_as_you_type("311000-2920");
+        assert!(formatted.kennitala().is_some());
+    }
+
+    #[test]
+    fn no_valid_completion() {
+        for (typed, error) in &[
+            ("32", KennitalaError::InvalidDay),
+            ("3102", KennitalaError::InvalidDay),
+            ("3113", KennitalaError::InvalidMonth),
+            ("3110001", KennitalaError::InvalidRandomDigits),
+            // The checksum digit would have to be 10.
+            ("31100025", KennitalaError::InvalidChecksum),
+            ("311000293", KennitalaError::InvalidChecksum),
+            ("3110002921", KennitalaError::InvalidCentury),
+        ] {
+            let formatted = format_as_you_type(typed);
+            assert_eq!(formatted.error(), Some(*error), "{:?}", typed);
+            assert!(formatted.next().is_empty());
+        }
+
+        let formatted = format_as_you_type("31a");
+        assert_eq!(formatted.display(), "31a");
+        assert_eq!(
+            formatted.error(),
+            Some(KennitalaError::InvalidNumber {
+                index: 2,
+                found: 'a'
+            })
+        );
+
+        let formatted = format_as_you_type("311000-29x");
+        assert_eq!(formatted.display(), "311000-29x");
+        assert_eq!(
+            formatted.error(),
+            Some(KennitalaError::InvalidNumber {
+                index: 9,
+                found: 'x'
+            })
+        );
+        let formatted = format_as_you_type("31100029x");
+        assert_eq!(
+            formatted.error(),
+            Some(KennitalaError::InvalidNumber {
+                index: 8,
+                found: 'x'
+            })
+        );
+    }
+}
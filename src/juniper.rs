@@ -0,0 +1,30 @@
+//! A GraphQL scalar for kennitölur through `juniper`. A kennitala is output as
+//! its canonical 10 digit string, and input as such a string.
+//!
+//! As with other scalars, juniper checks input kennitölur when validating the
+//! query, before executing it. An invalid one is therefore reported as a
+//! `GraphQLError::ValidationError` of the whole query, not as a field error.
+//! For variables, the message of the error includes that of the
+//! `KennitalaError`, but for literals in the query, juniper only reports that
+//! the value is invalid for the `Kennitala` type.
+use juniper::{graphql_scalar, InputValue, ScalarValue, Value};
+
+/// A kennitala, as its canonical 10 digit string.
+#[graphql_scalar]
+#[graphql(with = kennitala_scalar, parse_token(String))]
+type Kennitala = crate::Kennitala;
+
+mod kennitala_scalar {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(kennitala: &Kennitala) -> Value<S> {
+        Value::scalar(kennitala.to_string())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(value: &InputValue<S>) -> Result<Kennitala, String> {
+        let kennitala = value
+            .as_string_value()
+            .ok_or_else(|| format!("Expected `String`, found: {}", value))?;
+        Kennitala::new(kennitala).map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Kennitala, KennitalaError};
+
+/// The kind of holder a kennitala is issued to. System kennitölur
+/// (kerfiskennitölur), issued to individuals who are not in the national
+/// registry, begin with an `8` or a `9` and are not based on a date, so they
+/// are rejected when parsing and have no kind. This enum is non-exhaustive so
+/// that a variant for them can be added should they be supported.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum KennitalaKind {
+    /// An individual in the national registry. The first two digits are the
+    /// day of birth, from 01 to 31.
+    Person,
+    /// A company or other legal entity. The first two digits are the day of
+    /// registration plus 40, from 41 to 71.
+    Company,
+}
+
+impl Kennitala {
+    /// Get the kind of holder this kennitala is issued to.
+    #[inline]
+    pub fn kind(&self) -> KennitalaKind {
+        if self.is_company() {
+            KennitalaKind::Company
+        } else {
+            KennitalaKind::Person
+        }
+    }
+
+    /// Create new kennitala object from the given string, as with
+    /// `Kennitala::new`, along with the kind of holder it is issued to.
+    pub fn classify(kennitala: &str) -> Result<(Kennitala, KennitalaKind), KennitalaError> {
+        let kennitala = Kennitala::new(kennitala)?;
+        Ok((kennitala, kennitala.kind()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn person() {
+        for kt in &["3110002920", "0101012980", "0101502998"] {
+            assert_eq!(Kennitala::new(kt).unwrap().kind(), KennitalaKind::Person);
+        }
+    }
+
+    #[test]
+    fn company() {
+        for kt in &["6001692039", "7112993009", "4102102079"] {
+            assert_eq!(Kennitala::new(kt).unwrap().kind(), KennitalaKind::Company);
+        }
+    }
+
+    #[test]
+    fn classify() {
+        let cases = [
+            ("3110002920", KennitalaKind::Person),
+            ("0101012980", KennitalaKind::Person),
+            ("7112993009", KennitalaKind::Company),
+            ("4102102079", KennitalaKind::Company),
+        ];
+        for (input, kind) in &cases {
+            let kt = Kennitala::new(input).unwrap();
+            assert_eq!(Kennitala::classify(input), Ok((kt, *kind)));
+        }
+        assert_eq!(
+            Kennitala::classify("3110002921"),
+            Err(KennitalaError::InvalidCentury)
+        );
+    }
+}
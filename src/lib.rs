@@ -8,13 +8,76 @@
 //! individual's date of birth in DDMMYY format. The seventh and eight digits
 //! are randomly chosen when the kennitala is allocated, ranging from 22 to 99.
 //! The ninth digit is the checksum digit, and the tenth indicates the century
-//! of the individual's birth.
+//! of the individual's birth: `8` for the 1800s, `9` for the 1900s and `0` for
+//! the 2000s.
+//!
+//! ### Companies
+//! Companies and other legal entities are also issued kennitölur. These follow
+//! the same format, except that 40 is added to the day of registration, so the
+//! first two digits are in the range 41 to 71.
+//!
+//! ### System kennitölur
+//! Individuals who are not in the national registry may be issued a system
+//! kennitala (kerfiskennitala), which begins with an `8` or a `9` and is not
+//! based on a date. These are not supported, and are rejected when parsing.
 //!
 //! ### Checksum digit
 //! The dot product of the vector containing the first 8 digits of the kennitala
 //! is taken with the vector `[3, 2, 7, 6, 5, 4, 3, 2]`. Take the modulo 11 of
 //! that computation. If the result `r` is 0, the checksum digit is 0, otherwise it
 //! is `11 - r`.
+//!
+//! ## Optional features
+//! - `std` (enabled by default): Everything which needs the standard library,
+//!   such as `std::error::Error` for `KennitalaError`, and the functions which
+//!   allocate. Without it, the crate is `no_std`, and can still parse and
+//!   validate kennitölur.
+//! - `chrono` (enabled by default): Get the birthday of a kennitala's holder as
+//!   a `chrono::NaiveDate`. Implies `std`.
+//! - `actix-web`: Extract kennitölur in `actix-web` handlers, with a JSON
+//!   `400 Bad Request` response for invalid ones. Implies `serde`.
+//! - `arbitrary`: Generate plausible kennitölur for structured fuzzing with
+//!   `arbitrary`. Implies `std`.
+//! - `async-graphql`: Use kennitölur as a GraphQL scalar with `async-graphql`.
+//!   Implies `std`.
+//! - `axum`: Extract kennitölur from the path in `axum` handlers, with a
+//!   `422 Unprocessable Entity` response for invalid ones. Implies `serde`.
+//! - `borsh`: Serialize and deserialize kennitölur as their packed `u32` with
+//!   `borsh`. Implies `std`.
+//! - `bson`: Store kennitölur in MongoDB documents with `bson`, as strings or
+//!   as integers. Implies `serde`.
+//! - `bytemuck`: Cast slices of kennitölur to and from slices of their packed
+//!   `u32` with `bytemuck`.
+//! - `clap`: Parse kennitölur in command line arguments with `clap`. Implies
+//!   `std`.
+//! - `csv`: Validate the kennitala column of a CSV file. Implies `std`.
+//! - `ffi`: Validate kennitölur from C and other languages, through the
+//!   functions in the `ffi` module and the header `include/kennitolur.h`.
+//!   Implies `std`, which static and dynamic libraries need anyway.
+//! - `garde`: Validate kennitölur in structs deriving `garde::Validate`, with
+//!   the functions in the `garde` module. Implies `std`.
+//! - `juniper`: Use kennitölur as a GraphQL scalar with `juniper`. Implies
+//!   `std`.
+//! - `postgres`: Store kennitölur in PostgreSQL text columns with
+//!   `tokio-postgres` or `postgres`. Implies `std`.
+//! - `rand`: Generate random kennitölur for a given date of birth.
+//! - `rayon`: Validate large batches of kennitölur in parallel. Implies `std`.
+//! - `redis`: Use kennitölur as Redis keys and values with `redis`. Implies
+//!   `std`.
+//! - `rusqlite`: Store kennitölur in SQLite with `rusqlite`. Implies `std`.
+//! - `sea-orm`: Use kennitölur as columns of SeaORM entities. Implies `std`.
+//! - `serde`: Serialize and deserialize kennitölur as their canonical 10 digit
+//!   string, or as their packed `u32` in binary formats. Implies `std`.
+//! - `sqlx-postgres`: Store kennitölur in PostgreSQL text columns with `sqlx`.
+//!   Implies `std`.
+//! - `time`: Get the birthday of a kennitala's holder as a `time::Date`.
+//! - `utoipa`: Describe kennitölur in OpenAPI documents generated with
+//!   `utoipa`. Implies `std`.
+//! - `validator`: Validate kennitölur in structs deriving `validator::Validate`
+//!   with `validate_kennitala`. Implies `std`.
+//! - `wasm`: Validate kennitölur from JavaScript with `wasm-bindgen`. Implies
+//!   `std`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(
     missing_docs,
     future_incompatible,
@@ -26,16 +89,106 @@
     unsafe_code,
     unused_qualifications
 )]
+#[cfg(feature = "actix-web")]
+pub mod actix_web;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod century;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod date_prefix;
 mod dates;
 mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fixed;
+#[cfg(feature = "garde")]
+pub mod garde;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "std")]
+pub mod input;
+#[cfg(feature = "juniper")]
+mod juniper;
+mod kind;
+#[cfg(feature = "std")]
+mod lines;
+#[cfg(feature = "std")]
+mod normalize;
+#[cfg(feature = "std")]
+mod ocr;
+mod options;
+#[cfg(feature = "std")]
+mod packed;
+#[cfg(feature = "postgres")]
+mod postgres;
+mod prefix;
+#[cfg(feature = "std")]
+pub mod records;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+mod scan;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "sqlx-postgres")]
+mod sqlx;
+#[cfg(feature = "utoipa")]
+mod utoipa;
+#[cfg(feature = "validator")]
+mod validator;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 #[cfg(feature = "chrono")]
 use chrono::naive::NaiveDate;
-use std::convert::TryFrom;
-use std::fmt;
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, Local};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "rand")]
+use rand::Rng;
 
-use dates::days_in_month;
-pub use error::KennitalaError;
+#[cfg(feature = "validator")]
+pub use crate::validator::validate_kennitala;
+#[cfg(feature = "std")]
+pub use batch::validate_all;
+#[cfg(feature = "rayon")]
+pub use batch::validate_all_par;
+pub use century::Century;
+pub use date_prefix::DatePrefix;
+pub use dates::days_in_month;
+pub use error::{ErrorCounts, KennitalaError};
+pub use fixed::FixedKennitala;
+pub use kind::KennitalaKind;
+#[cfg(feature = "std")]
+pub use lines::{validate_lines, validate_lines_recording, ValidationStats};
+#[cfg(feature = "std")]
+pub use normalize::normalize;
+#[cfg(feature = "std")]
+pub use ocr::Corrected;
+pub use options::ParseOptions;
+#[cfg(feature = "std")]
+pub use packed::{validate_packed, PackedReport};
+pub use prefix::{DigitSet, PrefixStatus};
+pub use scan::{find_all, FindAll};
 
 const VALIDATION_DIGITS: [u8; 8] = [3, 2, 7, 6, 5, 4, 3, 2];
 
@@ -47,105 +200,418 @@ const YEAR_MASK: u32 = 0b00000000_00000000_11111110_00000000;
 const YEAR_OFFSET: u32 = MONTH_OFFSET + 4;
 const REST_MASK: u32 = 0b00000011_11111111_00000000_00000000;
 const REST_OFFSET: u32 = YEAR_OFFSET + 7;
-const CENTURY_MASK: u32 = 0b00000100_00000000_00000000_00000000;
+const CENTURY_MASK: u32 = 0b00001100_00000000_00000000_00000000;
 const CENTURY_OFFSET: u32 = REST_OFFSET + 10;
+const COMPANY_MASK: u32 = 0b00010000_00000000_00000000_00000000;
+const COMPANY_OFFSET: u32 = CENTURY_OFFSET + 2;
+const INVALID_CHECKSUM_MASK: u32 = 0b00100000_00000000_00000000_00000000;
+const INVALID_CHECKSUM_OFFSET: u32 = COMPANY_OFFSET + 1;
+
+/// The century digits, indexed by the value stored in the century bits. The
+/// order is such that kennitölur from the 1900s and 2000s have the same
+/// representation as when only these two centuries were supported.
+const CENTURY_DIGITS: [u8; 3] = [9, 0, 8];
+
+/// Companies have 40 added to the day of registration.
+const COMPANY_DAY_OFFSET: u32 = 40;
 
 /// Struct that represents the kennitala of an Icelandic citizen or resident.
+///
+/// The struct is `#[repr(transparent)]` over the `u32` returned by
+/// `Kennitala::as_u32`, so slices of kennitölur and of their packed
+/// representations have the same layout.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
 pub struct Kennitala {
     internal: u32,
 }
 
 impl Kennitala {
     /// Create new kennitala object from the given string. Validation is done
-    /// beforehand.
+    /// beforehand. The string must consist of exactly 10 digits; see
+    /// `Kennitala::new_lenient` for a constructor which also accepts the
+    /// hyphenated form.
     pub fn new(kennitala: &str) -> Result<Self, KennitalaError> {
-        let only_numbers = kennitala
-            .chars()
-            .all(|c| ((c as u32) >= 48) && ((c as u32) <= 57));
-        if !only_numbers {
-            return Err(KennitalaError::InvalidNumber);
+        Kennitala::from_bytes(kennitala.as_bytes()).map_err(|e| e.locate_in(kennitala, 0))
+    }
+
+    /// Create new kennitala object from the given string, ignoring any leading
+    /// or trailing ASCII whitespace, such as a trailing newline. Whitespace
+    /// within the kennitala is still rejected. Validation is done beforehand.
+    pub fn new_trimmed(kennitala: &str) -> Result<Self, KennitalaError> {
+        Kennitala::parse_with(kennitala, &ParseOptions::strict().trim_whitespace(true))
+    }
+
+    /// Create new kennitala object from the given ASCII bytes. Validation is
+    /// done beforehand. This accepts exactly the same input as `Kennitala::new`,
+    /// but avoids having to check that the bytes are valid UTF-8 first. Slices
+    /// of other lengths are rejected with `KennitalaError::InvalidLength`, and
+    /// bytes which are not ASCII digits with `KennitalaError::InvalidNumber`.
+    ///
+    /// This is convenient for fields at known offsets in fixed-width records,
+    /// which can be parsed without copying them. See the `records` module for
+    /// reading whole files of such records.
+    ///
+    /// ```
+    /// use kennitolur::{Kennitala, KennitalaError};
+    ///
+    /// let record = b"0001 Jon Jonsson       3110002920 Reykjavik";
+    /// let kt = Kennitala::from_bytes(&record[23..33]).unwrap();
+    /// assert_eq!(kt.to_string(), "3110002920");
+    /// assert_eq!(
+    ///     Kennitala::from_bytes(&record[23..32]),
+    ///     Err(KennitalaError::InvalidLength(9))
+    /// );
+    /// assert!(matches!(
+    ///     Kennitala::from_bytes(&record[22..32]),
+    ///     Err(KennitalaError::InvalidNumber { index: 0, found: ' ' })
+    /// ));
+    /// ```
+    pub const fn from_bytes(kennitala: &[u8]) -> Result<Self, KennitalaError> {
+        match ascii_to_array(kennitala) {
+            Ok(kennitala_array) => Kennitala::from_slice(&kennitala_array),
+            Err(e) => Err(e),
         }
+    }
 
-        if kennitala.len() != 10 {
-            // A valid kennitala string consists of 10 ASCII bytes.
-            return Err(KennitalaError::InvalidLength(kennitala.len()));
+    /// Create new kennitala object from the given ASCII bytes in a constant
+    /// context, which allows kennitölur to be validated at compile time:
+    /// ```
+    /// use kennitolur::Kennitala;
+    ///
+    /// const KENNITALA: Kennitala = Kennitala::from_bytes_const(b"3110002920");
+    /// assert_eq!(KENNITALA.get_day(), 31);
+    /// ```
+    ///
+    /// Invalid kennitölur fail to compile:
+    /// ```compile_fail
+    /// use kennitolur::Kennitala;
+    ///
+    /// const KENNITALA: Kennitala = Kennitala::from_bytes_const(b"3110002921");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the given kennitala is invalid. Use `Kennitala::from_bytes`
+    /// outside of constant contexts.
+    pub const fn from_bytes_const(kennitala: &[u8]) -> Self {
+        match Kennitala::from_bytes(kennitala) {
+            Ok(kt) => kt,
+            Err(KennitalaError::InvalidLength(_)) => panic!("invalid kennitala: wrong length"),
+            Err(KennitalaError::InvalidNumber { .. }) => panic!("invalid kennitala: not a number"),
+            Err(KennitalaError::InvalidDay) => panic!("invalid kennitala: invalid day"),
+            Err(KennitalaError::InvalidMonth) => panic!("invalid kennitala: invalid month"),
+            Err(KennitalaError::InvalidRandomDigits) => {
+                panic!("invalid kennitala: invalid random digits")
+            }
+            Err(KennitalaError::InvalidChecksum) => panic!("invalid kennitala: invalid checksum"),
+            Err(KennitalaError::InvalidCentury) => panic!("invalid kennitala: invalid century"),
+            Err(KennitalaError::DateOutOfRange) => panic!("invalid kennitala: date out of range"),
+            Err(KennitalaError::Placeholder) => panic!("invalid kennitala: placeholder"),
+            Err(KennitalaError::InvalidRepresentation) => {
+                panic!("invalid kennitala: invalid representation")
+            }
         }
+    }
 
-        let mut kennitala_array = <[u8; 10]>::try_from(kennitala.as_bytes()).unwrap();
-        for d in &mut kennitala_array {
-            // The ASCII codes for the arabic numerals share a contiguous range
-            // from 48 to 57.
-            *d -= 48;
+    /// Create new kennitala object from the given string, performing every
+    /// validation except for the checksum, for legacy data where kennitölur
+    /// with an incorrect checksum digit are still in use. Whether the checksum
+    /// is valid can be checked later with `Kennitala::has_valid_checksum`.
+    pub fn new_unchecked_checksum(kennitala: &str) -> Result<Self, KennitalaError> {
+        let kennitala_array =
+            ascii_to_array(kennitala.as_bytes()).map_err(|e| e.locate_in(kennitala, 0))?;
+        validate_fields(&kennitala_array)?;
+        let valid_checksum = kennitala_array[8] == calculate_checksum_digit(&kennitala_array);
+        Ok(Kennitala::pack(&kennitala_array, valid_checksum))
+    }
+
+    /// Create new kennitala object from the given string, replacing its
+    /// checksum digit with the correct one. Every other validation is
+    /// performed. Some random digits give a checksum of 10, which can't be
+    /// represented, and these are rejected with
+    /// `KennitalaError::InvalidChecksum`.
+    pub fn with_corrected_checksum(kennitala: &str) -> Result<Self, KennitalaError> {
+        let mut kennitala_array =
+            ascii_to_array(kennitala.as_bytes()).map_err(|e| e.locate_in(kennitala, 0))?;
+        validate_fields(&kennitala_array)?;
+        kennitala_array[8] = calculate_checksum_digit(&kennitala_array);
+        if kennitala_array[8] > 9 {
+            return Err(KennitalaError::InvalidChecksum);
         }
-        Kennitala::from_slice(&kennitala_array)
+        Ok(Kennitala::pack(&kennitala_array, true))
     }
 
-    // Create new kennitala object from the given u32. Validation is done
-    /// beforehand.
+    /// Create new kennitala object from the given string, which may contain a
+    /// single hyphen between the sixth and seventh digit, as in `311000-2920`.
+    /// A hyphen in any other position is rejected with
+    /// `KennitalaError::InvalidNumber`. Validation is done beforehand.
+    pub fn new_lenient(kennitala: &str) -> Result<Self, KennitalaError> {
+        Kennitala::parse_with(kennitala, &ParseOptions::strict().allow_separator('-'))
+    }
+
+    /// Create new kennitala object from the given u32. Validation is done
+    /// beforehand. As with `Kennitala::from_u64`, integers must have 9 or 10
+    /// digits, and 9 digits are read as a kennitala whose leading zero was
+    /// dropped, so `311203149` is `0311203149`. That is the only way to read
+    /// them, as every other digit of a kennitala is significant, and the
+    /// result is validated like any other.
     pub fn from_u32(kennitala_u32: u32) -> Result<Self, KennitalaError> {
         let mut kennitala = [0; 10];
-        kt_to_array(kennitala_u32, &mut kennitala)?;
+        kt_to_array(u64::from(kennitala_u32), &mut kennitala)?;
         Kennitala::from_slice(&kennitala)
     }
 
-    /// Create new kennitala object from the given slice. Validation is done
-    /// beforehand. Each element in the slice must be equal or less than 9.
-    fn from_slice(kennitala: &[u8; 10]) -> Result<Self, KennitalaError> {
-        debug_assert!(kennitala.iter().all(|d| *d <= 9));
+    /// Create new kennitala object from the given u64, such as a `BIGINT`
+    /// database column. Validation is done beforehand. Integers with more than
+    /// 10 digits, that is anything above `9999999999`, are rejected with
+    /// `KennitalaError::InvalidLength`. Kennitölur whose day starts with a
+    /// zero have 9 digits as integers, and are accepted.
+    ///
+    /// Company kennitölur can exceed `u32::MAX`, so unlike
+    /// `Kennitala::from_u32`, this accepts every valid kennitala.
+    pub fn from_u64(kennitala_u64: u64) -> Result<Self, KennitalaError> {
+        let mut kennitala = [0; 10];
+        kt_to_array(kennitala_u64, &mut kennitala)?;
+        Kennitala::from_slice(&kennitala)
+    }
 
-        let checksum_digit = kennitala[8];
-        let calculated_checksum_digit = calculate_checksum_digit(&kennitala);
-        if checksum_digit != calculated_checksum_digit {
-            return Err(KennitalaError::InvalidChecksum);
+    /// Create new kennitala object from the given digits, each of which must
+    /// be in the range [0, 9]. Validation is done beforehand, and a digit
+    /// greater than 9 is rejected with `KennitalaError::InvalidNumber`, with
+    /// `char::REPLACEMENT_CHARACTER` as the character found. This is the
+    /// inverse of `Kennitala::digits`.
+    ///
+    /// ```
+    /// use kennitolur::{Kennitala, KennitalaError};
+    ///
+    /// let digits = [3, 1, 1, 0, 0, 0, 2, 9, 2, 0];
+    /// assert_eq!(Kennitala::from_digits(digits).unwrap().digits(), digits);
+    /// assert_eq!(
+    ///     Kennitala::from_digits([3, 1, 1, 0, 0, 0, 2, 9, 12, 0]),
+    ///     Err(KennitalaError::InvalidNumber {
+    ///         index: 8,
+    ///         found: char::REPLACEMENT_CHARACTER
+    ///     })
+    /// );
+    /// ```
+    pub fn from_digits(digits: [u8; 10]) -> Result<Self, KennitalaError> {
+        if let Some(index) = digits.iter().position(|d| *d > 9) {
+            return Err(KennitalaError::InvalidNumber {
+                index,
+                found: char::REPLACEMENT_CHARACTER,
+            });
         }
+        Kennitala::from_slice(&digits)
+    }
 
-        if ((kennitala[6] * 10) + kennitala[7]) < 20 {
-            return Err(KennitalaError::InvalidRandomDigits);
-        }
+    /// Create new kennitala object from the given characters, without first
+    /// collecting them into a string. This accepts exactly the same input as
+    /// `Kennitala::new`, and returns the same errors, except that iteration
+    /// stops at the 11th character, so more than 10 characters are always
+    /// rejected with `KennitalaError::InvalidLength(11)`. This makes it safe
+    /// to use with endless iterators.
+    pub fn from_chars<I>(chars: I) -> Result<Self, KennitalaError>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        Kennitala::from_optional_digits(chars.into_iter().map(|c| c.to_digit(10).ok_or(c)))
+    }
 
-        let century_digit = kennitala[9] as u32;
-        if !((century_digit == 0) || (century_digit == 9)) {
-            return Err(KennitalaError::InvalidCentury);
-        }
-        let year_offset = if century_digit == 0 { 2000 } else { 1900 };
+    /// Create new kennitala object from the given digits, each of which must
+    /// be in the range [0, 9]. Otherwise, this behaves like
+    /// `Kennitala::from_chars`.
+    pub fn from_digit_iter<I>(digits: I) -> Result<Self, KennitalaError>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let digits = digits.into_iter().map(|d| {
+            if d <= 9 {
+                Ok(u32::from(d))
+            } else {
+                Err(char::REPLACEMENT_CHARACTER)
+            }
+        });
+        Kennitala::from_optional_digits(digits)
+    }
 
-        let dob_month = (kennitala[2] * 10) as u32 + kennitala[3] as u32;
-        if (dob_month > 12) || (dob_month <= 0) {
-            return Err(KennitalaError::InvalidMonth);
+    /// Create new kennitala object from the given digits, where an error holds
+    /// something which is not a digit.
+    fn from_optional_digits<I>(digits: I) -> Result<Self, KennitalaError>
+    where
+        I: Iterator<Item = Result<u32, char>>,
+    {
+        let mut kennitala = [0; 10];
+        let mut length = 0;
+        for digit in digits {
+            let d = kennitala
+                .get_mut(length)
+                .ok_or(KennitalaError::InvalidLength(length + 1))?;
+            *d = digit.map_err(|found| KennitalaError::InvalidNumber {
+                index: length,
+                found,
+            })? as u8;
+            length += 1;
         }
+        if length != 10 {
+            return Err(KennitalaError::InvalidLength(length));
+        }
+        Kennitala::from_slice(&kennitala)
+    }
 
-        let dob_year = (kennitala[4] * 10) as u32 + kennitala[5] as u32;
-
-        let dob_day = (kennitala[0] * 10) as u32 + kennitala[1] as u32;
-        if (dob_day > days_in_month(dob_month, dob_year + year_offset)) || (dob_day <= 0) {
-            return Err(KennitalaError::InvalidDay);
+    /// Create new kennitala object from the given slice. Validation is done
+    /// beforehand. Each element in the slice must be equal or less than 9.
+    const fn from_slice(kennitala: &[u8; 10]) -> Result<Self, KennitalaError> {
+        if let Err(e) = validate_slice(kennitala) {
+            return Err(e);
         }
+        Ok(Kennitala::pack(kennitala, true))
+    }
 
+    /// Pack the given kennitala, which must be valid apart from possibly its
+    /// checksum, into a kennitala object.
+    const fn pack(kennitala: &[u8; 10], valid_checksum: bool) -> Self {
+        let mut dob_day = (kennitala[0] * 10) as u32 + kennitala[1] as u32;
+        let is_company = dob_day > COMPANY_DAY_OFFSET;
+        if is_company {
+            dob_day -= COMPANY_DAY_OFFSET;
+        }
+        let dob_month = (kennitala[2] * 10) as u32 + kennitala[3] as u32;
+        let dob_year = (kennitala[4] * 10) as u32 + kennitala[5] as u32;
         let rest = (kennitala[6] as u32) * 100 + (kennitala[7] * 10) as u32 + kennitala[8] as u32;
+        let century = match kennitala[9] {
+            9 => 0,
+            0 => 1,
+            _ => 2,
+        };
+        debug_assert!(CENTURY_DIGITS[century as usize] == kennitala[9]);
 
         let mut value = dob_day << DAY_OFFSET;
         value += dob_month << MONTH_OFFSET;
         value += dob_year << YEAR_OFFSET;
         value += rest << REST_OFFSET;
-        value += ((century_digit == 0) as u32) << CENTURY_OFFSET;
+        value += century << CENTURY_OFFSET;
+        value += (is_company as u32) << COMPANY_OFFSET;
+        value += (!valid_checksum as u32) << INVALID_CHECKSUM_OFFSET;
 
-        Ok(Self { internal: value })
+        Self { internal: value }
     }
 
-    /// Get day in the range [1, 31]
+    /// Generate a random kennitala for an individual born on the given date.
+    /// The random digits are chosen uniformly from the ones which result in a
+    /// valid checksum digit. Dates outside of the years 1800 to 2099 are
+    /// rejected with `KennitalaError::DateOutOfRange`.
+    #[cfg(feature = "rand")]
+    pub fn generate_random<R: Rng + ?Sized>(
+        dob: NaiveDate,
+        rng: &mut R,
+    ) -> Result<Self, KennitalaError> {
+        loop {
+            match Kennitala::from_date(dob, rng.random_range(20..100)) {
+                // Roughly one in eleven choices of random digits results in a
+                // checksum of 10, which can't be represented.
+                Err(KennitalaError::InvalidChecksum) => continue,
+                result => return result,
+            }
+        }
+    }
+
+    /// Iterate over every kennitala of an individual born on the given date,
+    /// in order of their random digits, 20 through 99. Random digits which
+    /// result in a checksum of 10 are skipped, so there are usually around 73
+    /// of them rather than 80. Dates outside of the years 1800 to 2099 yield
+    /// nothing.
+    #[cfg(feature = "chrono")]
+    pub fn all_for_date(dob: NaiveDate) -> impl Iterator<Item = Kennitala> {
+        (20..100).filter_map(move |randoms| Kennitala::from_date(dob, randoms).ok())
+    }
+
+    /// Create new kennitala object for an individual born on the given date,
+    /// with the given random digits, for when full control is needed, such as
+    /// for deterministic test data. The checksum and century digits are
+    /// calculated from these.
+    ///
+    /// Random digits outside of 20 to 99 are rejected with
+    /// `KennitalaError::InvalidRandomDigits`, and dates outside of the years
+    /// 1800 to 2099 with `KennitalaError::DateOutOfRange`. Some random digits
+    /// result in a checksum of 10, which can't be represented, and are
+    /// rejected with `KennitalaError::InvalidChecksum`.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use kennitolur::Kennitala;
+    ///
+    /// let dob = NaiveDate::from_ymd_opt(2000, 10, 31).unwrap();
+    /// let kt = Kennitala::from_parts(dob, 29).unwrap();
+    /// assert_eq!(kt.to_string(), "3110002920");
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn from_parts(dob: NaiveDate, randoms: u8) -> Result<Self, KennitalaError> {
+        if !(20..=99).contains(&randoms) {
+            return Err(KennitalaError::InvalidRandomDigits);
+        }
+        Kennitala::from_date(dob, randoms)
+    }
+
+    /// Create new kennitala object for an individual born on the given date,
+    /// with the given random digits. The checksum and century digits are
+    /// calculated from these.
+    #[cfg(feature = "chrono")]
+    fn from_date(dob: NaiveDate, randoms: u8) -> Result<Self, KennitalaError> {
+        let century_digit = match dob.year() {
+            1800..=1899 => 8,
+            1900..=1999 => 9,
+            2000..=2099 => 0,
+            _ => return Err(KennitalaError::DateOutOfRange),
+        };
+        let two_digits = |n: u32| [(n / 10) as u8, (n % 10) as u8];
+        let [day_0, day_1] = two_digits(dob.day());
+        let [month_0, month_1] = two_digits(dob.month());
+        let [year_0, year_1] = two_digits(dob.year() as u32 % 100);
+        let [randoms_0, randoms_1] = two_digits(u32::from(randoms));
+        let mut kennitala = [
+            day_0,
+            day_1,
+            month_0,
+            month_1,
+            year_0,
+            year_1,
+            randoms_0,
+            randoms_1,
+            0,
+            century_digit,
+        ];
+        kennitala[8] = calculate_checksum_digit(&kennitala);
+        if kennitala[8] > 9 {
+            return Err(KennitalaError::InvalidChecksum);
+        }
+        Kennitala::from_digits(kennitala)
+    }
+
+    /// Get day in the range [1, 31]. For companies, this is the actual day of
+    /// registration, without the offset of 40.
     #[inline]
     pub fn get_day(&self) -> u32 {
         let day = (self.internal & DAY_MASK) >> DAY_OFFSET;
-        debug_assert!((day >= 1) && (day <= 31));
+        debug_assert!((1..=31).contains(&day));
         day
     }
 
+    /// Get the day as it is written in the kennitala, that is with an offset
+    /// of 40 for companies.
+    #[inline]
+    fn get_offset_day(&self) -> u32 {
+        if self.is_company() {
+            self.get_day() + COMPANY_DAY_OFFSET
+        } else {
+            self.get_day()
+        }
+    }
+
     /// Get month in the range [1, 12]
     #[inline]
     pub fn get_month(&self) -> u32 {
         let month = (self.internal & MONTH_MASK) >> MONTH_OFFSET;
-        debug_assert!((month >= 1) && (month <= 12));
+        debug_assert!((1..=12).contains(&month));
         month
     }
 
@@ -157,90 +623,719 @@ impl Kennitala {
         short_year
     }
 
-    /// Get year in the range [1900, 2099]
+    /// Get year in the range [1800, 2099]
     #[inline]
     pub fn get_year(&self) -> u32 {
-        let offset = if self.get_century_bit() == 0 {
-            1900
-        } else {
-            2000
+        let offset = match self.get_short_century() {
+            8 => 1800,
+            9 => 1900,
+            _ => 2000,
         };
         self.get_short_year() + offset
     }
 
-    /// Get the value of the bit storing which century this Kennitala's holder
-    /// was born in.
+    /// Get the year and month of birth, for grouping kennitölur without the
+    /// exact day. For companies, this is the year and month of registration.
+    ///
+    /// ```
+    /// use kennitolur::Kennitala;
+    ///
+    /// let kt = Kennitala::new("3110002920").unwrap();
+    /// assert_eq!(kt.birth_cohort(), (2000, 10));
+    /// ```
+    #[inline]
+    pub fn birth_cohort(&self) -> (u32, u32) {
+        (self.get_year(), self.get_month())
+    }
+
+    /// Check whether the two kennitölur have the same year and month of birth,
+    /// as given by `Kennitala::birth_cohort`.
+    #[inline]
+    pub fn same_cohort(&self, other: &Kennitala) -> bool {
+        self.birth_cohort() == other.birth_cohort()
+    }
+
+    /// Get the value of the bits storing which century this Kennitala's
+    /// holder was born in. This is an index into `CENTURY_DIGITS`.
     #[inline]
-    fn get_century_bit(&self) -> u32 {
-        let bit = (self.internal & CENTURY_MASK) >> CENTURY_OFFSET;
-        debug_assert!((bit == 0) || (bit == 1));
-        bit
+    fn get_century_bits(&self) -> u32 {
+        let bits = (self.internal & CENTURY_MASK) >> CENTURY_OFFSET;
+        debug_assert!(bits <= 2);
+        bits
     }
 
-    /// Get century digit in the set {0, 9}
+    /// Get century digit in the set {8, 9, 0}
     #[inline]
     pub fn get_short_century(&self) -> u32 {
-        if self.get_century_bit() == 0 {
-            9
-        } else {
-            0
-        }
+        u32::from(CENTURY_DIGITS[self.get_century_bits() as usize])
     }
 
     /// Get the two random digits plus the checksum digit, these are in the
-    /// range [20, 999]
+    /// range [20, 999]. See `Kennitala::get_random_pair` for the random digits
+    /// alone, and `Kennitala::get_checksum_digit` for the checksum digit.
     #[inline]
     pub fn get_randoms(&self) -> u32 {
         let randoms = (self.internal & REST_MASK) >> REST_OFFSET;
-        debug_assert!((randoms >= 20) && (randoms <= 999));
+        debug_assert!((20..=999).contains(&randoms));
         randoms
     }
 
-    /// Get the birthday of this kennitala's holder.
+    /// Get the two random digits, the seventh and eighth digits of the
+    /// kennitala, in the range [20, 99].
+    #[inline]
+    pub fn get_random_pair(&self) -> u8 {
+        (self.get_randoms() / 10) as u8
+    }
+
+    /// Get the checksum digit, the ninth digit of the kennitala. For a
+    /// kennitala created with `Kennitala::new_unchecked_checksum`, this is the
+    /// digit as written, which need not be the correct one.
+    #[inline]
+    pub fn get_checksum_digit(&self) -> u8 {
+        (self.get_randoms() % 10) as u8
+    }
+
+    /// Get the 10 digits of this kennitala, each in the range [0, 9]. These are
+    /// the same digits as those in the `Display` output.
+    pub fn digits(&self) -> [u8; 10] {
+        let day = self.get_offset_day();
+        let month = self.get_month();
+        let year = self.get_short_year();
+        let randoms = self.get_randoms();
+        [
+            (day / 10) as u8,
+            (day % 10) as u8,
+            (month / 10) as u8,
+            (month % 10) as u8,
+            (year / 10) as u8,
+            (year % 10) as u8,
+            (randoms / 100) as u8,
+            ((randoms / 10) % 10) as u8,
+            (randoms % 10) as u8,
+            self.get_short_century() as u8,
+        ]
+    }
+
+    /// Get this kennitala as a decimal number, such as `3110002920`, for
+    /// storage in an integer column. Kennitölur whose day starts with a zero
+    /// have 9 digits as integers. Company kennitölur can exceed `u32::MAX`.
+    pub fn to_decimal_u64(&self) -> u64 {
+        self.digits()
+            .iter()
+            .fold(0, |number, digit| number * 10 + u64::from(*digit))
+    }
+
+    /// Create new kennitala object from the decimal number returned by
+    /// `Kennitala::to_decimal_u64`. This is the same as `Kennitala::from_u64`.
+    #[inline]
+    pub fn from_decimal_u64(kennitala: u64) -> Result<Self, KennitalaError> {
+        Kennitala::from_u64(kennitala)
+    }
+
+    /// Get the packed representation of this kennitala, for compact storage.
+    /// This is not the kennitala as a decimal number, see `Kennitala::digits`
+    /// or the `Display` implementation for that. The bits, from the least
+    /// significant, are:
+    ///
+    /// | Bits    | Field                                                       |
+    /// |---------|-------------------------------------------------------------|
+    /// | 0–4     | Day of birth or registration, without the company offset    |
+    /// | 5–8     | Month                                                       |
+    /// | 9–15    | Last two digits of the year                                 |
+    /// | 16–25   | Random digits and checksum digit, as a number up to 999     |
+    /// | 26–27   | Century: `0` for the 1900s, `1` for the 2000s, `2` for the 1800s |
+    /// | 28      | Whether the kennitala belongs to a company                  |
+    /// | 29      | Whether the checksum digit is invalid                       |
+    /// | 30–31   | Unused, always zero                                         |
+    ///
+    /// The value can be turned back into a kennitala with
+    /// `Kennitala::from_packed` or `Kennitala::from_raw`. This layout is part
+    /// of the public API, and will not change without a major version bump,
+    /// so the value can be persisted.
+    #[inline]
+    pub fn as_u32(&self) -> u32 {
+        self.internal
+    }
+
+    /// Get the packed representation of this kennitala, with the stable layout
+    /// documented on `Kennitala::as_u32`. This is the same as
+    /// `Kennitala::as_u32`.
+    #[inline]
+    pub const fn to_raw(self) -> u32 {
+        self.internal
+    }
+
+    /// Create a kennitala object from the packed representation returned by
+    /// `Kennitala::to_raw`, validating every unpacked field. This is the same
+    /// as `validate_raw`.
+    #[inline]
+    pub fn from_raw(raw: u32) -> Result<Self, KennitalaError> {
+        validate_raw(raw)
+    }
+
+    /// Get the packed representation of this kennitala, as returned by
+    /// `Kennitala::to_raw`, as 4 big-endian bytes.
+    ///
+    /// ```
+    /// use kennitolur::Kennitala;
+    ///
+    /// let kt = Kennitala::new("3110002920").unwrap();
+    /// assert_eq!(kt.to_be_bytes(), [0x05, 0x24, 0x01, 0x5f]);
+    /// assert_eq!(Kennitala::from_be_bytes(kt.to_be_bytes()), Ok(kt));
+    /// ```
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.internal.to_be_bytes()
+    }
+
+    /// Create a kennitala object from the 4 big-endian bytes returned by
+    /// `Kennitala::to_be_bytes`, validating every unpacked field.
+    #[inline]
+    pub fn from_be_bytes(bytes: [u8; 4]) -> Result<Self, KennitalaError> {
+        validate_raw(u32::from_be_bytes(bytes))
+    }
+
+    /// Create a kennitala object from the packed representation returned by
+    /// `Kennitala::as_u32`, which documents the bit layout. Returns `None` if
+    /// the value is not the representation of a valid kennitala. Use
+    /// `Kennitala::from_u32` for the kennitala as a decimal number instead, or
+    /// `validate_raw` to find out why a value is not valid.
+    pub fn from_packed(packed: u32) -> Option<Self> {
+        validate_raw(packed).ok()
+    }
+
+    /// Reinterpret the given packed representation, as returned by
+    /// `Kennitala::as_u32`, as a kennitala without validating it. This is
+    /// never unsafe, but the getters of a kennitala created from a value which
+    /// `validate_raw` rejects return meaningless results, and may panic. Use
+    /// it only on values which are known to be valid, such as those written by
+    /// `Kennitala::as_u32`.
+    #[inline]
+    pub const fn from_raw_unvalidated(packed: u32) -> Self {
+        Kennitala { internal: packed }
+    }
+
+    /// Whether this kennitala belongs to a company or other legal entity,
+    /// rather than an individual.
+    #[inline]
+    pub fn is_company(&self) -> bool {
+        (self.internal & COMPANY_MASK) >> COMPANY_OFFSET == 1
+    }
+
+    /// Whether the checksum digit of this kennitala is correct. This is always
+    /// the case unless it was created with `Kennitala::new_unchecked_checksum`.
+    #[inline]
+    pub fn has_valid_checksum(&self) -> bool {
+        (self.internal & INVALID_CHECKSUM_MASK) >> INVALID_CHECKSUM_OFFSET == 0
+    }
+
+    /// Get this kennitala in the form conventionally used for display, with a
+    /// hyphen between the sixth and seventh digit, as in `311000-2920`. The
+    /// `Display` implementation gives the bare 10 digits instead, unless the
+    /// alternate flag is used, as in `format!("{:#}", kennitala)`.
+    #[cfg(feature = "std")]
+    pub fn to_string_pretty(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    /// Get this kennitala with the last four digits masked, as in `311000****`,
+    /// for example for audit logs where entries for the same holder should
+    /// still be possible to correlate. Note that the first six digits are the
+    /// holder's date of birth, which may itself be sensitive personal data;
+    /// use `Kennitala::fully_masked` to reveal nothing.
+    #[cfg(feature = "std")]
+    pub fn masked(&self) -> String {
+        format!("{}****", &self.to_fixed().as_str()[..6])
+    }
+
+    /// Get a fully masked kennitala, `**********`, which has the same length
+    /// as a kennitala but reveals nothing about it.
+    #[inline]
+    pub fn fully_masked(&self) -> &'static str {
+        "**********"
+    }
+
+    /// Get the birthday of this kennitala's holder. For companies, this is the
+    /// date of registration, with the offset of 40 removed from the day. Use
+    /// `Kennitala::date_of_birth` or `Kennitala::registration_date` to tell
+    /// the two apart.
     #[cfg(feature = "chrono")]
     pub fn get_birthday(&self) -> NaiveDate {
-        NaiveDate::from_ymd(self.get_year() as i32, self.get_month(), self.get_day())
+        NaiveDate::from_ymd_opt(self.get_year() as i32, self.get_month(), self.get_day())
+            .expect("a validated kennitala always holds a valid date")
+    }
+
+    /// Get the date of birth of this kennitala's holder, or `None` if it
+    /// belongs to a company, whose kennitala holds a date of registration
+    /// instead.
+    #[cfg(feature = "chrono")]
+    pub fn date_of_birth(&self) -> Option<NaiveDate> {
+        if self.is_company() {
+            None
+        } else {
+            Some(self.get_birthday())
+        }
+    }
+
+    /// Get the date of registration of the company this kennitala belongs to,
+    /// or `None` if it belongs to an individual.
+    #[cfg(feature = "chrono")]
+    pub fn registration_date(&self) -> Option<NaiveDate> {
+        if self.is_company() {
+            Some(self.get_birthday())
+        } else {
+            None
+        }
+    }
+
+    /// Get the birthday of this kennitala's holder as a `time::Date`.
+    #[cfg(feature = "time")]
+    pub fn birthday_time(&self) -> time::Date {
+        let month = time::Month::try_from(self.get_month() as u8)
+            .expect("a validated kennitala always holds a valid month");
+        time::Date::from_calendar_date(self.get_year() as i32, month, self.get_day() as u8)
+            .expect("a validated kennitala always holds a valid date")
+    }
+
+    /// Get the age of this kennitala's holder today, in completed years,
+    /// according to the local time zone. For companies, this is the number of
+    /// years since registration.
+    #[cfg(feature = "chrono")]
+    pub fn age(&self) -> u32 {
+        let age = self.age_on(Local::now().date_naive());
+        // Kennitölur with a date of birth in the future have an age of zero.
+        u32::try_from(age).unwrap_or(0)
+    }
+
+    /// Get the age of this kennitala's holder on the given date, in completed
+    /// years. The age is negative if the given date is before the birthday of
+    /// the holder, minus one year. People born on the 29th of February get
+    /// one year older on the 1st of March in years that are not leap years.
+    #[cfg(feature = "chrono")]
+    pub fn age_on(&self, date: NaiveDate) -> i64 {
+        let birthday = self.get_birthday();
+        let mut age = i64::from(date.year() - birthday.year());
+        if (date.month(), date.day()) < (birthday.month(), birthday.day()) {
+            age -= 1;
+        }
+        age
     }
 }
 
+/// Kennitölur are ordered chronologically by date of birth, and then by their
+/// random digits (including the checksum digit). Any remaining ties are broken
+/// by the internal representation, so that the ordering is always consistent
+/// with equality.
+impl Ord for Kennitala {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_year()
+            .cmp(&other.get_year())
+            .then(self.get_month().cmp(&other.get_month()))
+            .then(self.get_day().cmp(&other.get_day()))
+            .then(self.get_randoms().cmp(&other.get_randoms()))
+            .then(self.internal.cmp(&other.internal))
+    }
+}
+
+impl PartialOrd for Kennitala {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Formats the kennitala as its 10 digits, as in `3110002920`. With the
+/// alternate flag, `{:#}`, a hyphen is inserted after the sixth digit, as in
+/// `311000-2920`.
 impl fmt::Display for Kennitala {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{:02}{:02}{:02}{:03}{}",
-            self.get_day(),
+            "{:02}{:02}{:02}{}{:03}{}",
+            self.get_offset_day(),
             self.get_month(),
             self.get_short_year(),
+            if f.alternate() { "-" } else { "" },
             self.get_randoms(),
             self.get_short_century()
         )
     }
 }
 
-fn kt_to_array(kt_integer: u32, array: &mut [u8; 10]) -> Result<(), KennitalaError> {
-    let mut n = kt_integer;
+/// Create a `Kennitala` from a string literal, which is validated at compile
+/// time.
+///
+/// ```
+/// use kennitolur::{kt, Kennitala};
+///
+/// let kennitala = kt!("3110002920");
+/// assert_eq!(kennitala, Kennitala::new("3110002920").unwrap());
+/// ```
+///
+/// Invalid kennitölur fail to compile:
+/// ```compile_fail
+/// let kennitala = kennitolur::kt!("3110002921");
+/// ```
+#[macro_export]
+macro_rules! kt {
+    ($kennitala:expr) => {{
+        // A constant item is always evaluated, even by `cargo check`. Its
+        // name is unlikely to shadow a constant used in `$kennitala`.
+        const __KENNITOLUR_KT: $crate::Kennitala =
+            $crate::Kennitala::from_bytes_const($kennitala.as_bytes());
+        __KENNITOLUR_KT
+    }};
+}
+
+/// Check whether the given string is a valid kennitala, without constructing a
+/// `Kennitala`. This performs the same checks as `Kennitala::new`, and returns
+/// the same errors.
+#[inline]
+pub fn validate(kennitala: &str) -> Result<(), KennitalaError> {
+    let kennitala_array =
+        ascii_to_array(kennitala.as_bytes()).map_err(|e| e.locate_in(kennitala, 0))?;
+    validate_slice(&kennitala_array)
+}
+
+/// Check whether the given string is a valid kennitala, that is whether
+/// `Kennitala::new` would succeed. Use `validate` to find out why it is not.
+///
+/// ```
+/// let valid: Vec<&str> = ["3110002920", "3110002921", "311000-2920"]
+///     .iter()
+///     .copied()
+///     .filter(|s| kennitolur::is_valid(s))
+///     .collect();
+/// assert_eq!(valid, ["3110002920"]);
+/// ```
+#[inline]
+pub fn is_valid(kennitala: &str) -> bool {
+    match ascii_to_array(kennitala.as_bytes()) {
+        Ok(kennitala_array) => validate_slice(&kennitala_array).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Get the kennitala of the oldest holder among the given kennitölur, that is
+/// the one with the earliest date of birth, or `None` if there are none. This
+/// is the minimum according to the `Ord` implementation, so ties between
+/// holders born on the same day are broken by the random digits. Companies are
+/// compared by their date of registration.
+///
+/// ```
+/// use kennitolur::Kennitala;
+///
+/// let kennitolur = ["0311203149", "1703715939", "3110002920"];
+/// let oldest = kennitolur::oldest(kennitolur.iter().map(|s| Kennitala::new(s).unwrap()));
+/// // Born in 1920, 1971 and 2000 respectively.
+/// assert_eq!(oldest.unwrap().to_string(), "0311203149");
+/// ```
+pub fn oldest<I>(kennitolur: I) -> Option<Kennitala>
+where
+    I: IntoIterator<Item = Kennitala>,
+{
+    kennitolur.into_iter().min()
+}
+
+/// Get the kennitala of the youngest holder among the given kennitölur, that
+/// is the one with the latest date of birth, or `None` if there are none. This
+/// is the maximum according to the `Ord` implementation, as with `oldest`.
+pub fn youngest<I>(kennitolur: I) -> Option<Kennitala>
+where
+    I: IntoIterator<Item = Kennitala>,
+{
+    kennitolur.into_iter().max()
+}
+
+/// Get the correct checksum digit, the ninth digit, for the given first eight
+/// digits of a kennitala, each in the range [0, 9]. Returns `None` if the
+/// checksum would be 10, in which case no kennitala starts with these digits,
+/// or if any of the digits is greater than 9. The date and random digits are
+/// not validated.
+///
+/// ```
+/// assert_eq!(kennitolur::expected_checksum_digit(&[3, 1, 1, 0, 0, 0, 2, 9]), Some(2));
+/// assert_eq!(kennitolur::expected_checksum_digit(&[3, 1, 1, 0, 0, 0, 2, 5]), None);
+/// ```
+pub const fn expected_checksum_digit(first_eight: &[u8; 8]) -> Option<u8> {
+    let mut kennitala = [0; 10];
+    let mut i = 0;
+    while i < 8 {
+        if first_eight[i] > 9 {
+            return None;
+        }
+        kennitala[i] = first_eight[i];
+        i += 1;
+    }
+    match calculate_checksum_digit(&kennitala) {
+        10 => None,
+        digit => Some(digit),
+    }
+}
+
+/// Check that the given value is the packed representation of a valid
+/// kennitala, as returned by `Kennitala::as_u32`, and return the kennitala.
+/// The unpacked fields are validated as with `Kennitala::new`, except for the
+/// checksum, which need only agree with the flag in bit 29, so that the
+/// representation of a kennitala created with `Kennitala::new_unchecked_checksum`
+/// is accepted. Values which can not be unpacked into digits, have unused bits
+/// set or have an incorrect checksum flag are rejected with
+/// `KennitalaError::InvalidRepresentation`.
+///
+/// ```
+/// use kennitolur::{validate_raw, Kennitala, KennitalaError};
+///
+/// let kt = Kennitala::new("3110002920").unwrap();
+/// assert_eq!(validate_raw(kt.as_u32()), Ok(kt));
+/// assert_eq!(
+///     validate_raw(kt.as_u32() | (1 << 30)),
+///     Err(KennitalaError::InvalidRepresentation)
+/// );
+/// ```
+pub fn validate_raw(packed: u32) -> Result<Kennitala, KennitalaError> {
+    let century_bits = ((packed & CENTURY_MASK) >> CENTURY_OFFSET) as usize;
+    let century = *CENTURY_DIGITS
+        .get(century_bits)
+        .ok_or(KennitalaError::InvalidCentury)?;
+    let mut day = (packed & DAY_MASK) >> DAY_OFFSET;
+    if (packed & COMPANY_MASK) != 0 {
+        day += COMPANY_DAY_OFFSET;
+    }
+    let month = (packed & MONTH_MASK) >> MONTH_OFFSET;
+    let year = (packed & YEAR_MASK) >> YEAR_OFFSET;
+    let rest = (packed & REST_MASK) >> REST_OFFSET;
+    let digits = [
+        (day / 10) as u8,
+        (day % 10) as u8,
+        (month / 10) as u8,
+        (month % 10) as u8,
+        (year / 10) as u8,
+        (year % 10) as u8,
+        (rest / 100) as u8,
+        ((rest / 10) % 10) as u8,
+        (rest % 10) as u8,
+        century,
+    ];
+    if !all_digits(&digits) {
+        return Err(KennitalaError::InvalidRepresentation);
+    }
+    validate_fields(&digits)?;
+    let valid_checksum = digits[8] == calculate_checksum_digit(&digits);
+    let kt = Kennitala::pack(&digits, valid_checksum);
+    if kt.internal == packed {
+        Ok(kt)
+    } else {
+        // Either unused bits are set, or the checksum flag is incorrect. A
+        // company day of 0 is turned into 40 above, and rejected as a day.
+        Err(KennitalaError::InvalidRepresentation)
+    }
+}
+
+/// Validate the given slice. Each element in the slice must be equal or less
+/// than 9.
+const fn validate_slice(kennitala: &[u8; 10]) -> Result<(), KennitalaError> {
+    debug_assert!(all_digits(kennitala));
+
+    if is_placeholder(kennitala) {
+        return Err(KennitalaError::Placeholder);
+    }
+
+    let checksum_digit = kennitala[8];
+    let calculated_checksum_digit = calculate_checksum_digit(kennitala);
+    if checksum_digit != calculated_checksum_digit {
+        return Err(KennitalaError::InvalidChecksum);
+    }
+
+    validate_fields(kennitala)
+}
+
+/// Validate every field of the given slice apart from the checksum digit. Each
+/// element in the slice must be equal or less than 9.
+const fn validate_fields(kennitala: &[u8; 10]) -> Result<(), KennitalaError> {
+    if is_placeholder(kennitala) {
+        return Err(KennitalaError::Placeholder);
+    }
+
+    if ((kennitala[6] * 10) + kennitala[7]) < 20 {
+        return Err(KennitalaError::InvalidRandomDigits);
+    }
+
+    let year_offset = match kennitala[9] {
+        8 => 1800,
+        9 => 1900,
+        0 => 2000,
+        _ => return Err(KennitalaError::InvalidCentury),
+    };
+
+    let dob_month = (kennitala[2] * 10) as u32 + kennitala[3] as u32;
+    let dob_year = (kennitala[4] * 10) as u32 + kennitala[5] as u32;
+    let days = match days_in_month(dob_month, dob_year + year_offset) {
+        Some(days) => days as u32,
+        None => return Err(KennitalaError::InvalidMonth),
+    };
+
+    let mut dob_day = (kennitala[0] * 10) as u32 + kennitala[1] as u32;
+    if dob_day > COMPANY_DAY_OFFSET {
+        dob_day -= COMPANY_DAY_OFFSET;
+    }
+    if (dob_day > days) || (dob_day == 0) {
+        return Err(KennitalaError::InvalidDay);
+    }
+
+    Ok(())
+}
+
+/// Whether all digits of the given slice are the same, as in `0000000000`,
+/// which some systems use in place of a missing kennitala.
+const fn is_placeholder(kennitala: &[u8; 10]) -> bool {
+    let mut i = 1;
+    while i < 10 {
+        if kennitala[i] != kennitala[0] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl FromStr for Kennitala {
+    type Err = KennitalaError;
+
+    fn from_str(kennitala: &str) -> Result<Self, Self::Err> {
+        Kennitala::new(kennitala)
+    }
+}
+
+impl TryFrom<&str> for Kennitala {
+    type Error = KennitalaError;
+
+    fn try_from(kennitala: &str) -> Result<Self, Self::Error> {
+        Kennitala::new(kennitala)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Kennitala {
+    type Error = KennitalaError;
+
+    fn try_from(kennitala: String) -> Result<Self, Self::Error> {
+        Kennitala::new(&kennitala)
+    }
+}
+
+/// The canonical 10 digit form, as with `Display`.
+#[cfg(feature = "std")]
+impl From<Kennitala> for String {
+    fn from(kennitala: Kennitala) -> Self {
+        kennitala.to_string()
+    }
+}
+
+/// The canonical 10 digit form, as with `Display`.
+#[cfg(feature = "std")]
+impl From<&Kennitala> for String {
+    fn from(kennitala: &Kennitala) -> Self {
+        kennitala.to_string()
+    }
+}
+
+impl TryFrom<u32> for Kennitala {
+    type Error = KennitalaError;
+
+    fn try_from(kennitala: u32) -> Result<Self, Self::Error> {
+        Kennitala::from_u32(kennitala)
+    }
+}
+
+impl TryFrom<u64> for Kennitala {
+    type Error = KennitalaError;
+
+    fn try_from(kennitala: u64) -> Result<Self, Self::Error> {
+        Kennitala::from_u64(kennitala)
+    }
+}
+
+/// The bytes are expected to be ASCII digits. No UTF-8 validation takes place,
+/// since any byte outside of the ASCII digit range is rejected anyway.
+impl TryFrom<&[u8]> for Kennitala {
+    type Error = KennitalaError;
+
+    fn try_from(kennitala: &[u8]) -> Result<Self, Self::Error> {
+        Kennitala::from_bytes(kennitala)
+    }
+}
+
+const fn ascii_to_array(kennitala: &[u8]) -> Result<[u8; 10], KennitalaError> {
+    let mut i = 0;
+    while i < kennitala.len() {
+        if !kennitala[i].is_ascii_digit() {
+            let found = if kennitala[i].is_ascii() {
+                kennitala[i] as char
+            } else {
+                char::REPLACEMENT_CHARACTER
+            };
+            return Err(KennitalaError::InvalidNumber { index: i, found });
+        }
+        i += 1;
+    }
+
+    if kennitala.len() != 10 {
+        // A valid kennitala string consists of 10 ASCII bytes.
+        return Err(KennitalaError::InvalidLength(kennitala.len()));
+    }
+
+    let mut kennitala_array = [0; 10];
+    let mut i = 0;
+    while i < 10 {
+        // The ASCII codes for the arabic numerals share a contiguous range
+        // from 48 to 57.
+        kennitala_array[i] = kennitala[i] - 48;
+        i += 1;
+    }
+    Ok(kennitala_array)
+}
+
+const fn all_digits(kennitala: &[u8; 10]) -> bool {
     let mut i = 0;
+    while i < 10 {
+        if kennitala[i] > 9 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+fn kt_to_array(kt_integer: u64, array: &mut [u8; 10]) -> Result<(), KennitalaError> {
+    let mut length = 0;
+    let mut n = kt_integer;
     while n > 0 {
-        let digit = n % 10;
-        debug_assert!(digit <= 9);
-        array[9 - i] = digit as u8;
         n /= 10;
-        i += 1
+        length += 1;
     }
-    if i < 9 {
-        Err(KennitalaError::InvalidLength(i))
-    } else {
-        Ok(())
+    // Kennitölur whose day of birth starts with a zero have 9 digits when
+    // written as an integer.
+    if !(9..=10).contains(&length) {
+        return Err(KennitalaError::InvalidLength(length));
+    }
+
+    let mut n = kt_integer;
+    for d in array.iter_mut().rev() {
+        *d = (n % 10) as u8;
+        n /= 10;
     }
+    debug_assert_eq!(n, 0);
+    Ok(())
 }
 
 // This function can return the number 10, which is not a valid digit in the
 // range [0, 9]. That's okay, since the number 10 will not match the checksum
 // digit in the given kennitala, so an error will be raised.
-fn calculate_checksum_digit(kennitala: &[u8; 10]) -> u8 {
+const fn calculate_checksum_digit(kennitala: &[u8; 10]) -> u8 {
     let mut sum: u32 = 0;
-    for i in 0..8 {
+    let mut i = 0;
+    while i < 8 {
         sum += (kennitala[i] * VALIDATION_DIGITS[i]) as u32;
+        i += 1;
     }
     let sum_mod_11 = sum % 11;
     let digit = if sum_mod_11 == 0 { 0 } else { 11 - sum_mod_11 };
@@ -265,9 +1360,15 @@ mod tests {
         assert_eq!(my_kennitala.get_year(), 2000);
         #[cfg(feature = "chrono")]
         {
-            let my_birthday = NaiveDate::from_ymd(2000, 10, 31);
+            let my_birthday = NaiveDate::from_ymd_opt(2000, 10, 31).unwrap();
             assert_eq!(my_kennitala.get_birthday(), my_birthday);
         }
+        #[cfg(feature = "time")]
+        {
+            let my_birthday =
+                time::Date::from_calendar_date(2000, time::Month::October, 31).unwrap();
+            assert_eq!(my_kennitala.birthday_time(), my_birthday);
+        }
         assert_eq!(my_kennitala.to_string(), "3110002920");
     }
 
@@ -275,16 +1376,22 @@ mod tests {
     fn my_moms_kennitala() {
         let my_moms_kennitala = Kennitala::new("1703715939").unwrap();
         assert_eq!(my_moms_kennitala.get_day(), 17);
-        assert_eq!(my_moms_kennitala.get_month(), 03);
+        assert_eq!(my_moms_kennitala.get_month(), 3);
         assert_eq!(my_moms_kennitala.get_short_year(), 71);
         assert_eq!(my_moms_kennitala.get_short_century(), 9);
         assert_eq!(my_moms_kennitala.get_randoms(), 593);
         assert_eq!(my_moms_kennitala.get_year(), 1971);
         #[cfg(feature = "chrono")]
         {
-            let my_moms_birthday = NaiveDate::from_ymd(1971, 03, 17);
+            let my_moms_birthday = NaiveDate::from_ymd_opt(1971, 3, 17).unwrap();
             assert_eq!(my_moms_kennitala.get_birthday(), my_moms_birthday);
         }
+        #[cfg(feature = "time")]
+        {
+            let my_moms_birthday =
+                time::Date::from_calendar_date(1971, time::Month::March, 17).unwrap();
+            assert_eq!(my_moms_kennitala.birthday_time(), my_moms_birthday);
+        }
         assert_eq!(my_moms_kennitala.to_string(), "1703715939");
     }
 
@@ -299,33 +1406,1117 @@ mod tests {
         assert_eq!(kt.get_year(), 1920);
         #[cfg(feature = "chrono")]
         {
-            let my_moms_birthday = NaiveDate::from_ymd(1920, 11, 3);
+            let my_moms_birthday = NaiveDate::from_ymd_opt(1920, 11, 3).unwrap();
             assert_eq!(kt.get_birthday(), my_moms_birthday);
         }
+        #[cfg(feature = "time")]
+        {
+            let birthday = time::Date::from_calendar_date(1920, time::Month::November, 3).unwrap();
+            assert_eq!(kt.birthday_time(), birthday);
+        }
         assert_eq!(kt.to_string(), "0311203149");
     }
 
+    #[test]
+    fn lenient_accepts_hyphen() {
+        let kt = Kennitala::new_lenient("311000-2920").unwrap();
+        assert_eq!(kt, Kennitala::new("3110002920").unwrap());
+        assert_eq!(kt.to_string(), "3110002920");
+        assert_eq!(Kennitala::new_lenient("3110002920").unwrap(), kt);
+    }
+
+    #[test]
+    fn lenient_rejects_misplaced_hyphens() {
+        for kt_str in &[
+            "31100-02920",
+            "3110002-920",
+            "311000--2920",
+            "-3110002920",
+            "3110002920-",
+            "311000-29-20",
+            "311000-292",
+            "311000-29200",
+        ] {
+            let kt = Kennitala::new_lenient(kt_str);
+            assert!(matches!(kt, Err(KennitalaError::InvalidNumber { .. })));
+        }
+        assert_eq!(
+            Kennitala::new("311000-2920"),
+            Err(KennitalaError::InvalidNumber {
+                index: 6,
+                found: '-'
+            })
+        );
+    }
+
+    #[test]
+    fn parse_from_str() {
+        let kt: Kennitala = "1703715939".parse().unwrap();
+        assert_eq!(kt, Kennitala::new("1703715939").unwrap());
+        assert_eq!(
+            "1703715949".parse::<Kennitala>(),
+            Err(KennitalaError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_from_agrees_with_new() {
+        for kt_str in &[
+            "3110002920",
+            "311000292",
+            "31100029200",
+            "3110\x002920",
+            "311000292\x00",
+            "3110002921",
+            "",
+        ] {
+            let expected = Kennitala::new(kt_str);
+            let from_str = Kennitala::try_from(*kt_str);
+            let from_string = Kennitala::try_from(kt_str.to_string());
+            let from_bytes = Kennitala::try_from(kt_str.as_bytes());
+            assert_eq!(from_str, expected);
+            assert_eq!(from_string, expected);
+            assert_eq!(from_bytes, expected);
+        }
+
+        // Bytes are not decoded, so the offending character is not known.
+        let kt = Kennitala::try_from("31100029é".as_bytes());
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 8,
+                found: char::REPLACEMENT_CHARACTER
+            })
+        );
+        let kt = Kennitala::try_from("31100029é");
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 8,
+                found: 'é'
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_non_utf8_bytes() {
+        let kt = Kennitala::try_from(&b"3110\xff\xfe2920"[..]);
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 4,
+                found: char::REPLACEMENT_CHARACTER
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_number_position() {
+        let kt = Kennitala::new("31100a2920");
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 5,
+                found: 'a'
+            })
+        );
+        let kt = Kennitala::new("3110ö02920");
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 4,
+                found: 'ö'
+            })
+        );
+        let kt = Kennitala::from_chars("311000 2920".chars());
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 6,
+                found: ' '
+            })
+        );
+        let kt = Kennitala::from_digits([3, 1, 1, 0, 0, 0, 2, 9, b'2', 0]);
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 8,
+                found: char::REPLACEMENT_CHARACTER
+            })
+        );
+        assert_eq!(
+            Kennitala::new("31100a2920").unwrap_err().to_string(),
+            "Invalid character 'a' at position 5"
+        );
+    }
+
+    #[test]
+    fn company_kennitala() {
+        let kt = Kennitala::new("6001692039").unwrap();
+        assert!(kt.is_company());
+        assert_eq!(kt.get_day(), 20);
+        assert_eq!(kt.get_month(), 1);
+        assert_eq!(kt.get_year(), 1969);
+        assert_eq!(kt.get_randoms(), 203);
+        assert_eq!(kt.to_string(), "6001692039");
+
+        let kt = Kennitala::new("7112993009").unwrap();
+        assert!(kt.is_company());
+        assert_eq!(kt.get_day(), 31);
+        assert_eq!(kt.get_month(), 12);
+        assert_eq!(kt.get_year(), 1999);
+        assert_eq!(kt.to_string(), "7112993009");
+
+        let kt = Kennitala::new("4102102079").unwrap();
+        assert!(kt.is_company());
+        assert_eq!(kt.get_day(), 1);
+        assert_eq!(kt.get_year(), 1910);
+        assert_eq!(kt.to_string(), "4102102079");
+
+        assert!(!Kennitala::new("3110002920").unwrap().is_company());
+    }
+
+    #[test]
+    fn unchecked_checksum() {
+        let kt = Kennitala::new_unchecked_checksum("3110002930").unwrap();
+        assert!(!kt.has_valid_checksum());
+        assert_eq!(kt.get_day(), 31);
+        assert_eq!(kt.get_month(), 10);
+        assert_eq!(kt.get_year(), 2000);
+        assert_eq!(kt.get_randoms(), 293);
+        assert_eq!(kt.digits(), [3, 1, 1, 0, 0, 0, 2, 9, 3, 0]);
+        assert_eq!(kt.to_string(), "3110002930");
+        assert_ne!(kt, Kennitala::new("3110002920").unwrap());
+
+        let kt = Kennitala::new_unchecked_checksum("7112993019").unwrap();
+        assert!(!kt.has_valid_checksum());
+        assert!(kt.is_company());
+        assert_eq!(kt.to_string(), "7112993019");
+    }
+
+    #[test]
+    fn unchecked_checksum_of_valid_kennitala() {
+        let kt = Kennitala::new_unchecked_checksum("3110002920").unwrap();
+        assert!(kt.has_valid_checksum());
+        assert_eq!(kt, Kennitala::new("3110002920").unwrap());
+        assert!(Kennitala::new("1703715939").unwrap().has_valid_checksum());
+    }
+
+    #[test]
+    fn corrected_checksum() {
+        let kt = Kennitala::with_corrected_checksum("3110002950").unwrap();
+        assert_eq!(kt, Kennitala::new("3110002920").unwrap());
+        assert!(kt.has_valid_checksum());
+        assert_eq!(kt.get_checksum_digit(), 2);
+        let kt = Kennitala::with_corrected_checksum("7112993009").unwrap();
+        assert_eq!(kt, Kennitala::new("7112993009").unwrap());
+        // The random digits 25 give a checksum of 10.
+        let kt = Kennitala::with_corrected_checksum("3110002500");
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
+        let kt = Kennitala::with_corrected_checksum("3111002950");
+        assert_eq!(kt, Err(KennitalaError::InvalidDay));
+        let kt = Kennitala::with_corrected_checksum("3110001950");
+        assert_eq!(kt, Err(KennitalaError::InvalidRandomDigits));
+    }
+
+    #[test]
+    fn unchecked_checksum_still_validates() {
+        let kt = Kennitala::new_unchecked_checksum("3113002930");
+        assert_eq!(kt, Err(KennitalaError::InvalidMonth));
+        let kt = Kennitala::new_unchecked_checksum("3110001930");
+        assert_eq!(kt, Err(KennitalaError::InvalidRandomDigits));
+        let kt = Kennitala::new_unchecked_checksum("3110002931");
+        assert_eq!(kt, Err(KennitalaError::InvalidCentury));
+        let kt = Kennitala::new_unchecked_checksum("311000293");
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(9)));
+    }
+
+    #[test]
+    fn decimal_u64_round_trip() {
+        for kt_str in &[
+            "3110002920",
+            "0311203149",
+            "0101502998",
+            "7112993009",
+            "4301993079",
+        ] {
+            let kt = Kennitala::new(kt_str).unwrap();
+            let decimal = kt.to_decimal_u64();
+            assert_eq!(decimal, kt_str.parse::<u64>().unwrap());
+            assert_eq!(format!("{:010}", decimal), kt.to_string());
+            assert_eq!(Kennitala::from_decimal_u64(decimal), Ok(kt));
+        }
+        assert_eq!(
+            Kennitala::from_decimal_u64(3110002930),
+            Err(KennitalaError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn packed_round_trip() {
+        for kt in &[
+            "3110002920",
+            "1703715939",
+            "0101502998",
+            "2902002290",
+            "7112993009",
+            "4301993079",
+        ] {
+            let kt = Kennitala::new(kt).unwrap();
+            assert_eq!(Kennitala::from_packed(kt.as_u32()), Some(kt));
+        }
+        let kt = Kennitala::new_unchecked_checksum("3110002930").unwrap();
+        assert_eq!(Kennitala::from_packed(kt.as_u32()), Some(kt));
+    }
+
+    #[test]
+    fn packed_layout() {
+        let kt = Kennitala::new("3110002920").unwrap();
+        let packed = kt.as_u32();
+        assert_eq!(packed & 0b11111, 31);
+        assert_eq!((packed >> 5) & 0b1111, 10);
+        assert_eq!((packed >> 9) & 0b1111111, 0);
+        assert_eq!((packed >> 16) & 0b11_1111_1111, 292);
+        assert_eq!((packed >> 26) & 0b11, 1);
+        assert_eq!(packed >> 28, 0);
+        let kt = Kennitala::new("7112993009").unwrap();
+        assert_eq!(kt.as_u32() & 0b11111, 31);
+        assert_eq!((kt.as_u32() >> 28) & 1, 1);
+    }
+
+    #[test]
+    fn packed_invalid() {
+        let packed = Kennitala::new("3110002920").unwrap().as_u32();
+        // Unused bits.
+        assert_eq!(Kennitala::from_packed(packed | (1 << 30)), None);
+        // The checksum flag, although the checksum is valid.
+        assert_eq!(Kennitala::from_packed(packed | (1 << 29)), None);
+        // A fourth century.
+        assert_eq!(Kennitala::from_packed(packed | (0b11 << 26)), None);
+        // The 32nd of October.
+        assert_eq!(Kennitala::from_packed(packed + 1), None);
+        // Random digits above 999.
+        assert_eq!(
+            Kennitala::from_packed(packed | (0b11_1111_1111 << 16)),
+            None
+        );
+        // A year above 99.
+        assert_eq!(Kennitala::from_packed(packed | (0b1111111 << 9)), None);
+        assert_eq!(Kennitala::from_packed(0), None);
+        assert_eq!(Kennitala::from_packed(u32::MAX), None);
+    }
+
+    #[test]
+    fn raw_golden_values() {
+        // These values are persisted by users, and must never change.
+        for (kt_str, raw) in &[
+            ("3110002920", 0x0524_015f),
+            ("1703715939", 0x0251_8e71),
+            ("0101502998", 0x092b_6421),
+            ("7112993009", 0x112c_c79f),
+        ] {
+            let kt = Kennitala::new(kt_str).unwrap();
+            assert_eq!(kt.to_raw(), *raw, "{}", kt_str);
+            assert_eq!(kt.to_be_bytes(), raw.to_be_bytes());
+            assert_eq!(Kennitala::from_raw(*raw), Ok(kt));
+            assert_eq!(Kennitala::from_be_bytes(raw.to_be_bytes()), Ok(kt));
+        }
+        let kt = Kennitala::new_unchecked_checksum("3110002930").unwrap();
+        assert_eq!(kt.to_raw(), 0x2525_015f);
+        assert_eq!(Kennitala::from_raw(0x2525_015f), Ok(kt));
+        assert_eq!(
+            Kennitala::from_be_bytes([0xff; 4]),
+            Err(KennitalaError::InvalidCentury)
+        );
+    }
+
+    #[test]
+    fn validate_raw_errors() {
+        let packed = Kennitala::new("3110002920").unwrap().as_u32();
+        let representation = Err(KennitalaError::InvalidRepresentation);
+        assert_eq!(validate_raw(packed | (1 << 30)), representation);
+        assert_eq!(validate_raw(packed | (1 << 29)), representation);
+        assert_eq!(
+            validate_raw(packed | (0b11 << 26)),
+            Err(KennitalaError::InvalidCentury)
+        );
+        assert_eq!(validate_raw(packed + 1), Err(KennitalaError::InvalidDay));
+        assert_eq!(
+            validate_raw(packed | (0b11_1111_1111 << 16)),
+            representation
+        );
+        assert_eq!(validate_raw(packed | (0b1111111 << 9)), representation);
+        assert_eq!(validate_raw(0), Err(KennitalaError::InvalidRandomDigits));
+
+        let kt = Kennitala::new_unchecked_checksum("3110002930").unwrap();
+        assert_eq!(validate_raw(kt.as_u32()), Ok(kt));
+        assert_eq!(
+            validate_raw(kt.as_u32() & !(1 << 29)),
+            Err(KennitalaError::InvalidRepresentation)
+        );
+        assert_eq!(Kennitala::from_raw_unvalidated(kt.as_u32()), kt);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn all_for_date() {
+        let dob = NaiveDate::from_ymd_opt(2000, 10, 31).unwrap();
+        let all: Vec<Kennitala> = Kennitala::all_for_date(dob).collect();
+        // Not 80, since 7 choices of random digits give a checksum of 10.
+        assert_eq!(all.len(), 73);
+        assert!(all.contains(&Kennitala::new("3110002920").unwrap()));
+        assert!(all
+            .windows(2)
+            .all(|w| w[0].get_randoms() < w[1].get_randoms()));
+        for kt in &all {
+            assert_eq!(kt.get_birthday(), dob);
+            assert!(!kt.is_company());
+        }
+
+        let dob = NaiveDate::from_ymd_opt(2100, 1, 1).unwrap();
+        assert_eq!(Kennitala::all_for_date(dob).count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn from_parts() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let cases = [
+            (date(2000, 10, 31), 29, "3110002920"),
+            (date(1971, 3, 17), 59, "1703715939"),
+            (date(1920, 11, 3), 31, "0311203149"),
+            (date(1850, 1, 1), 29, "0101502998"),
+            (date(1804, 2, 29), 22, "2902042248"),
+        ];
+        for (dob, randoms, expected) in &cases {
+            let kt = Kennitala::from_parts(*dob, *randoms).unwrap();
+            assert_eq!(kt.to_string(), *expected);
+            assert_eq!(Kennitala::new(&kt.to_string()), Ok(kt));
+            assert_eq!(kt.get_birthday(), *dob);
+            assert_eq!(kt.get_random_pair(), *randoms);
+            assert!(!kt.is_company());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn from_parts_errors() {
+        let dob = NaiveDate::from_ymd_opt(2000, 10, 31).unwrap();
+        for randoms in &[0, 19, 100, 255] {
+            assert_eq!(
+                Kennitala::from_parts(dob, *randoms),
+                Err(KennitalaError::InvalidRandomDigits)
+            );
+        }
+        let randoms = (20..100)
+            .find(|r| Kennitala::from_parts(dob, *r).is_err())
+            .unwrap();
+        assert_eq!(
+            Kennitala::from_parts(dob, randoms),
+            Err(KennitalaError::InvalidChecksum)
+        );
+        for dob in &[
+            NaiveDate::from_ymd_opt(1799, 12, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2100, 1, 1).unwrap(),
+        ] {
+            assert_eq!(
+                Kennitala::from_parts(*dob, 29),
+                Err(KennitalaError::DateOutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn birth_cohort() {
+        let kt = |s| Kennitala::new(s).unwrap();
+        assert_eq!(kt("3110002920").birth_cohort(), (2000, 10));
+        assert_eq!(kt("0110002070").birth_cohort(), (2000, 10));
+        // The day of companies is offset by 40, but the month is not.
+        assert_eq!(kt("7110002080").birth_cohort(), (2000, 10));
+        assert_eq!(kt("7112993009").birth_cohort(), (1999, 12));
+        assert_eq!(kt("0101502998").birth_cohort(), (1850, 1));
+    }
+
+    #[test]
+    fn same_cohort() {
+        let kt = |s| Kennitala::new(s).unwrap();
+        let october_2000 = kt("3110002920");
+        assert!(october_2000.same_cohort(&october_2000));
+        assert!(october_2000.same_cohort(&kt("0110002070")));
+        assert!(kt("0110002070").same_cohort(&october_2000));
+        assert!(october_2000.same_cohort(&kt("7110002080")));
+        // A different month of the same year.
+        assert!(!october_2000.same_cohort(&kt("3009002080")));
+        // The same month of a different year.
+        assert!(!october_2000.same_cohort(&kt("3110992059")));
+    }
+
+    #[test]
+    fn random_pair() {
+        let cases = [
+            ("3110002920", 29),
+            ("1703715939", 59),
+            ("0311203149", 31),
+            ("7112993009", 30),
+        ];
+        for (kt, pair) in &cases {
+            let kt = Kennitala::new(kt).unwrap();
+            assert_eq!(kt.get_random_pair(), *pair);
+            assert_eq!(
+                kt.get_randoms(),
+                u32::from(kt.get_random_pair()) * 10 + u32::from(kt.get_checksum_digit())
+            );
+        }
+    }
+
+    #[test]
+    fn checksum_digit() {
+        for kt in &["3110002920", "1703715939", "0311203149", "7112993009"] {
+            let kt = Kennitala::new(kt).unwrap();
+            assert_eq!(
+                kt.get_checksum_digit(),
+                calculate_checksum_digit(&kt.digits())
+            );
+            assert_eq!(kt.get_checksum_digit(), kt.digits()[8]);
+        }
+        let kt = Kennitala::new_unchecked_checksum("3110002930").unwrap();
+        assert_eq!(kt.get_checksum_digit(), 3);
+    }
+
+    #[test]
+    fn company_day_out_of_range() {
+        // 31st of April, plus 40.
+        let kt = Kennitala::new("7104993069");
+        assert_eq!(kt, Err(KennitalaError::InvalidDay));
+        // Days 32 to 40 are neither valid for individuals nor companies.
+        let kt = Kennitala::new("4001002090");
+        assert_eq!(kt, Err(KennitalaError::InvalidDay));
+    }
+
+    #[test]
+    fn try_from_integers() {
+        let kt = Kennitala::new("3110002920").unwrap();
+        assert_eq!(Kennitala::try_from(3110002920u32).unwrap(), kt);
+        assert_eq!(Kennitala::try_from(3110002920u64).unwrap(), kt);
+
+        // Company kennitölur can be larger than `u32::MAX`.
+        let kt = Kennitala::try_from(7112993009u64).unwrap();
+        assert_eq!(kt.to_string(), "7112993009");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn display_alternate() {
+        for (bare, pretty) in &[
+            ("3110002920", "311000-2920"),
+            ("0311203149", "031120-3149"),
+            ("0101302129", "010130-2129"),
+            ("7112993009", "711299-3009"),
+        ] {
+            let kt = Kennitala::new(bare).unwrap();
+            assert_eq!(format!("{}", kt), *bare);
+            assert_eq!(format!("{:#}", kt), *pretty);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn to_string_pretty() {
+        for (bare, pretty) in &[
+            ("3110002920", "311000-2920"),
+            ("0311203149", "031120-3149"),
+            ("7112993009", "711299-3009"),
+        ] {
+            let kt = Kennitala::new(bare).unwrap();
+            assert_eq!(kt.to_string(), *bare);
+            assert_eq!(kt.to_string_pretty(), *pretty);
+            assert_eq!(Kennitala::new_lenient(pretty).unwrap(), kt);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_into_in_generic_context() {
+        use std::convert::TryInto;
+
+        fn parse<T>(value: T) -> Result<Kennitala, KennitalaError>
+        where
+            T: TryInto<Kennitala, Error = KennitalaError>,
+        {
+            let kt = value.try_into()?;
+            Ok(kt)
+        }
+
+        let kt = Kennitala::new("3110002920").unwrap();
+        assert_eq!(parse("3110002920").unwrap(), kt);
+        assert_eq!(parse(String::from("3110002920")).unwrap(), kt);
+        assert_eq!(parse(3110002920u32).unwrap(), kt);
+        assert_eq!(parse("3110002930"), Err(KennitalaError::InvalidChecksum));
+        assert_eq!(
+            parse(String::from("31100029")),
+            Err(KennitalaError::InvalidLength(8))
+        );
+        assert_eq!(parse(3110002930u32), Err(KennitalaError::InvalidChecksum));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn into_string() {
+        fn owned<T: Into<String>>(value: T) -> String {
+            value.into()
+        }
+
+        for input in &["3110002920", "0311203149", "7112993009"] {
+            let kt = Kennitala::new(input).unwrap();
+            let s: String = kt.into();
+            assert_eq!(s, kt.to_string());
+            assert_eq!(String::from(&kt), kt.to_string());
+            assert_eq!(owned(kt), *input);
+        }
+    }
+
+    #[test]
+    fn try_from_integers_with_leading_zero() {
+        let kt = Kennitala::try_from(311203149u32).unwrap();
+        assert_eq!(kt.to_string(), "0311203149");
+        let kt = Kennitala::try_from(311203149u64).unwrap();
+        assert_eq!(kt.to_string(), "0311203149");
+    }
+
+    #[test]
+    fn from_integers_nine_digits() {
+        // `3110002920` with its last digit dropped is read as `0311000292`,
+        // which is rejected, rather than as anything with 10 digits.
+        let kt = Kennitala::from_u32(311000292);
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
+        let kt = Kennitala::from_u64(311000292);
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
+        // As is `1703715939` with its last digit dropped.
+        let kt = Kennitala::from_u32(170371593);
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
+    }
+
+    #[test]
+    fn from_integers_round_trip() {
+        // Any integer which is accepted is the decimal form of the kennitala
+        // it was read as, so nothing is accepted as a different kennitala.
+        for n in (311_203_000..311_204_000).chain(3_110_002_000..3_110_003_000) {
+            let from_u64 = Kennitala::from_u64(n);
+            if let Ok(kt) = from_u64 {
+                assert_eq!(kt.to_decimal_u64(), n);
+            }
+            assert_eq!(Kennitala::from_u32(n as u32), from_u64);
+        }
+        assert_eq!(
+            Kennitala::from_u64(311203149).unwrap().to_decimal_u64(),
+            311203149
+        );
+    }
+
+    #[test]
+    fn try_from_integers_out_of_range() {
+        let kt = Kennitala::try_from(10_000_000_000u64);
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(11)));
+        let kt = Kennitala::try_from(u64::MAX);
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(20)));
+        let kt = Kennitala::try_from(31100029u64);
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(8)));
+        let kt = Kennitala::try_from(0u64);
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn from_bytes() {
+        let kt = Kennitala::from_bytes(b"1703715939").unwrap();
+        assert_eq!(kt, Kennitala::new("1703715939").unwrap());
+        let kt = Kennitala::from_bytes(b"170371593");
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(9)));
+        let kt = Kennitala::from_bytes(b"17037159\x809");
+        assert!(matches!(kt, Err(KennitalaError::InvalidNumber { .. })));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn age_on() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let kt = Kennitala::new("3110002920").unwrap();
+        assert_eq!(kt.age_on(date(2000, 10, 31)), 0);
+        assert_eq!(kt.age_on(date(2020, 10, 30)), 19);
+        assert_eq!(kt.age_on(date(2020, 10, 31)), 20);
+        assert_eq!(kt.age_on(date(2021, 1, 1)), 20);
+        assert_eq!(kt.age_on(date(2000, 1, 1)), -1);
+        let today = Local::now().date_naive();
+        assert_eq!(i64::from(kt.age()), kt.age_on(today));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn age_on_leap_day() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let kt = Kennitala::new("2902002290").unwrap();
+        assert_eq!(kt.get_birthday(), date(2000, 2, 29));
+        assert_eq!(kt.age_on(date(2001, 2, 28)), 0);
+        assert_eq!(kt.age_on(date(2001, 3, 1)), 1);
+        assert_eq!(kt.age_on(date(2004, 2, 28)), 3);
+        assert_eq!(kt.age_on(date(2004, 2, 29)), 4);
+    }
+
+    #[test]
+    fn ordering() {
+        let mut kennitalas: Vec<Kennitala> = [
+            "3110002920",
+            "0311203149",
+            "1703715939",
+            "7112993009",
+            "3112993019",
+            "0101012980",
+            "0311203229",
+        ]
+        .iter()
+        .map(|kt| Kennitala::new(kt).unwrap())
+        .collect();
+        kennitalas.sort();
+        let sorted: Vec<String> = kennitalas.iter().map(|kt| kt.to_string()).collect();
+        assert_eq!(
+            sorted,
+            [
+                "0311203149",
+                "0311203229",
+                "1703715939",
+                "7112993009",
+                "3112993019",
+                "3110002920",
+                "0101012980",
+            ]
+        );
+    }
+
+    #[test]
+    fn from_digits() {
+        let kt = Kennitala::from_digits([3, 1, 1, 0, 0, 0, 2, 9, 2, 0]).unwrap();
+        assert_eq!(kt, Kennitala::new("3110002920").unwrap());
+        let kt = Kennitala::from_digits([3, 1, 1, 0, 0, 0, 2, 9, 2, 1]);
+        assert_eq!(kt, Err(KennitalaError::InvalidCentury));
+    }
+
+    #[test]
+    fn from_digits_out_of_range() {
+        let found = char::REPLACEMENT_CHARACTER;
+        let kt = Kennitala::from_digits([3, 1, 1, 0, 0, 0, 2, 9, 12, 0]);
+        assert_eq!(kt, Err(KennitalaError::InvalidNumber { index: 8, found }));
+        let kt = Kennitala::from_digits([3, 1, 1, 0, 0, 0, 2, 9, 2, 255]);
+        assert_eq!(kt, Err(KennitalaError::InvalidNumber { index: 9, found }));
+        let kt = Kennitala::from_digits([10; 10]);
+        assert_eq!(kt, Err(KennitalaError::InvalidNumber { index: 0, found }));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_random() {
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut date = NaiveDate::from_ymd_opt(1996, 2, 27).unwrap();
+        for _ in 0..1000 {
+            let kt = Kennitala::generate_random(date, &mut rng).unwrap();
+            assert_eq!(Kennitala::new(&kt.to_string()).unwrap(), kt);
+            assert_eq!(kt.get_birthday(), date);
+            assert!(!kt.is_company());
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_random_out_of_range() {
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        for (y, m, d) in &[(1799, 12, 31), (2100, 1, 1)] {
+            let date = NaiveDate::from_ymd_opt(*y, *m, *d).unwrap();
+            let kt = Kennitala::generate_random(date, &mut rng);
+            assert_eq!(kt, Err(KennitalaError::DateOutOfRange));
+            assert_eq!(
+                kt.unwrap_err().to_string(),
+                "Date of birth is not between the years 1800 and 2099"
+            );
+        }
+    }
+
+    #[test]
+    fn trimmed() {
+        let kt = Kennitala::new_trimmed("\t3110002920\r\n").unwrap();
+        assert_eq!(kt, Kennitala::new("3110002920").unwrap());
+        let kt = Kennitala::new_trimmed("  3110002920");
+        assert!(kt.is_ok());
+        let kt = Kennitala::new_trimmed("31 10002920");
+        assert!(matches!(kt, Err(KennitalaError::InvalidNumber { .. })));
+        let kt = Kennitala::new_trimmed(" 311000292\n");
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(9)));
+    }
+
+    #[test]
+    fn validate_agrees_with_new() {
+        for kt_str in &[
+            "3110002920",
+            "7112993009",
+            "3110002921",
+            "3110002930",
+            "3213002920",
+            "311000292",
+            "31100O2920",
+            "31100Ó2920",
+        ] {
+            let expected = Kennitala::new(kt_str).map(|_| ());
+            assert_eq!(validate(kt_str), expected);
+        }
+    }
+
+    #[test]
+    fn is_valid_agrees_with_new() {
+        for kt_str in &[
+            "3110002920",
+            "1703715939",
+            "0311203149",
+            "7112993009",
+            "3110002921",
+            "3110002930",
+            "3213002920",
+            "311000292",
+            "311000-2920",
+            "31100O2920",
+            "",
+        ] {
+            assert_eq!(
+                is_valid(kt_str),
+                Kennitala::new(kt_str).is_ok(),
+                "{}",
+                kt_str
+            );
+        }
+        assert!(is_valid("3110002920"));
+        assert!(!is_valid("3110002921"));
+    }
+
+    #[test]
+    fn placeholders() {
+        for digit in 0..10 {
+            let kt_str = digit.to_string().repeat(10);
+            let placeholder = Err(KennitalaError::Placeholder);
+            assert_eq!(Kennitala::new(&kt_str), placeholder, "{}", kt_str);
+            assert_eq!(validate(&kt_str), Err(KennitalaError::Placeholder));
+            assert!(!is_valid(&kt_str));
+            assert_eq!(Kennitala::new_unchecked_checksum(&kt_str), placeholder);
+            assert_eq!(Kennitala::with_corrected_checksum(&kt_str), placeholder);
+            if digit > 0 {
+                assert_eq!(Kennitala::from_u64(kt_str.parse().unwrap()), placeholder);
+            }
+        }
+        // The checksum of 1111111111 is correct, but its random digits are not.
+        assert_eq!(
+            Kennitala::new("0000000000"),
+            Err(KennitalaError::Placeholder)
+        );
+        assert_eq!(
+            Kennitala::new("1111111111"),
+            Err(KennitalaError::Placeholder)
+        );
+        assert_eq!(
+            Kennitala::new("1111111121"),
+            Err(KennitalaError::InvalidChecksum)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn masked() {
+        for (kt_str, masked) in &[
+            ("3110002920", "311000****"),
+            ("1703715939", "170371****"),
+            ("0311203149", "031120****"),
+            ("7112993009", "711299****"),
+        ] {
+            let kt = Kennitala::new(kt_str).unwrap();
+            assert_eq!(kt.masked(), *masked);
+            assert_eq!(kt.fully_masked(), "**********");
+            assert_eq!(kt.fully_masked().len(), kt_str.len());
+        }
+    }
+
+    #[test]
+    fn oldest_and_youngest() {
+        let kennitolur: Vec<Kennitala> = [
+            "3110002920",
+            "0101502998",
+            "1703715939",
+            "0311203149",
+            "2902042248",
+            "7112993009",
+        ]
+        .iter()
+        .map(|s| Kennitala::new(s).unwrap())
+        .collect();
+        let first = oldest(kennitolur.iter().copied()).unwrap();
+        assert_eq!(first.to_string(), "2902042248");
+        assert_eq!(first.get_year(), 1804);
+        let last = youngest(kennitolur.iter().copied()).unwrap();
+        assert_eq!(last.to_string(), "3110002920");
+        assert_eq!(last.get_year(), 2000);
+
+        // Born on the same day, so the random digits decide.
+        let twins = [
+            Kennitala::new("3110003140").unwrap(),
+            Kennitala::new("3110002920").unwrap(),
+        ];
+        assert_eq!(oldest(twins.iter().copied()), Some(twins[1]));
+        assert_eq!(youngest(twins.iter().copied()), Some(twins[0]));
+
+        assert_eq!(oldest(Vec::new()), None);
+        assert_eq!(youngest(core::iter::empty()), None);
+    }
+
+    #[test]
+    fn expected_checksum() {
+        assert_eq!(expected_checksum_digit(&[3, 1, 1, 0, 0, 0, 2, 9]), Some(2));
+        // The weighted sum is divisible by 11.
+        assert_eq!(expected_checksum_digit(&[3, 1, 1, 0, 0, 0, 3, 3]), Some(0));
+        assert!(Kennitala::new("3110003300").is_ok());
+        // The weighted sum leaves a remainder of 1, so the checksum would be 10.
+        assert_eq!(expected_checksum_digit(&[3, 1, 1, 0, 0, 0, 2, 5]), None);
+        assert_eq!(expected_checksum_digit(&[3, 1, 1, 0, 0, 0, 2, 10]), None);
+        for kt_str in &["3110002920", "1703715939", "0311203149", "7112993009"] {
+            let kt = Kennitala::new(kt_str).unwrap();
+            let mut first_eight = [0; 8];
+            first_eight.copy_from_slice(&kt.digits()[..8]);
+            assert_eq!(
+                expected_checksum_digit(&first_eight),
+                Some(kt.get_checksum_digit())
+            );
+        }
+    }
+
+    #[test]
+    fn from_bytes_const() {
+        const KENNITALA: Kennitala = Kennitala::from_bytes_const(b"1703715939");
+        assert_eq!(KENNITALA, Kennitala::new("1703715939").unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid kennitala: invalid checksum")]
+    fn from_bytes_const_invalid() {
+        let kennitala = *b"1703715949";
+        Kennitala::from_bytes_const(&kennitala);
+    }
+
+    #[test]
+    fn kt_macro() {
+        assert_eq!(kt!("0311203149"), Kennitala::new("0311203149").unwrap());
+        const KENNITALA: &str = "7112993009";
+        assert!(kt!(KENNITALA).is_company());
+    }
+
+    #[test]
+    fn digits() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        assert_eq!(kt.digits(), [0, 3, 1, 1, 2, 0, 3, 1, 4, 9]);
+        for kt_str in &["3110002920", "1703715939", "7112993009", "0101012980"] {
+            let kt = Kennitala::new(kt_str).unwrap();
+            let digits = String::from_utf8(kt.digits().map(|d| d + b'0').to_vec()).unwrap();
+            assert_eq!(digits, kt.to_string());
+            assert_eq!(Kennitala::from_digits(kt.digits()).unwrap(), kt);
+        }
+    }
+
+    #[test]
+    fn from_chars() {
+        let kt = Kennitala::from_chars("3110002920".chars()).unwrap();
+        assert_eq!(kt, Kennitala::new("3110002920").unwrap());
+        let kt = Kennitala::from_chars("311000-2920".chars().filter(|c| *c != '-'));
+        assert!(kt.is_ok());
+        for kt_str in &["311000292", "31100029200", "", "3110a02920", "3110002921"] {
+            assert_eq!(
+                Kennitala::from_chars(kt_str.chars()),
+                Kennitala::new(kt_str)
+            );
+        }
+        for kt_str in &["3110002920a", "31100029201a", "311000292012"] {
+            assert_eq!(
+                Kennitala::from_chars(kt_str.chars()),
+                Err(KennitalaError::InvalidLength(11))
+            );
+        }
+    }
+
+    #[test]
+    fn from_chars_endless() {
+        assert_eq!(
+            Kennitala::from_chars(core::iter::repeat('1')),
+            Err(KennitalaError::InvalidLength(11))
+        );
+        let mut chars = "3110002920".chars().cycle();
+        assert_eq!(
+            Kennitala::from_chars(chars.by_ref()),
+            Err(KennitalaError::InvalidLength(11))
+        );
+        // Nothing past the 11th character is consumed.
+        assert_eq!(chars.next(), Some('1'));
+        assert_eq!(
+            Kennitala::from_digit_iter(core::iter::repeat(1)),
+            Err(KennitalaError::InvalidLength(11))
+        );
+    }
+
+    #[test]
+    fn from_digit_iter() {
+        let digits = vec![3, 1, 1, 0, 0, 0, 2, 9, 2, 0];
+        let kt = Kennitala::from_digit_iter(digits).unwrap();
+        assert_eq!(kt, Kennitala::new("3110002920").unwrap());
+        let kt = Kennitala::from_digit_iter(vec![3, 1, 1, 0, 0]);
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(5)));
+        let kt = Kennitala::from_digit_iter(vec![1; 12]);
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(11)));
+        let kt = Kennitala::from_digit_iter(vec![3, 1, 1, 0, 10, 0, 2, 9, 2, 0]);
+        let found = char::REPLACEMENT_CHARACTER;
+        assert_eq!(kt, Err(KennitalaError::InvalidNumber { index: 4, found }));
+    }
+
+    #[test]
+    fn from_u64() {
+        let kt = Kennitala::from_u64(3110002920).unwrap();
+        assert_eq!(kt, Kennitala::new("3110002920").unwrap());
+        let kt = Kennitala::from_u64(101012980).unwrap();
+        assert_eq!(kt.to_string(), "0101012980");
+    }
+
+    #[test]
+    fn from_u64_around_u32_max() {
+        let max = u64::from(u32::MAX);
+        assert_eq!(
+            Kennitala::from_u64(max),
+            Err(KennitalaError::InvalidChecksum)
+        );
+        assert_eq!(
+            Kennitala::from_u64(max + 1),
+            Err(KennitalaError::InvalidChecksum)
+        );
+
+        // Company kennitölur just below and above `u32::MAX`.
+        let below = Kennitala::from_u64(4212993079).unwrap();
+        assert!(below.is_company());
+        assert_eq!(Kennitala::from_u32(4212993079).unwrap(), below);
+        let above = Kennitala::from_u64(4301993079).unwrap();
+        assert!(above.is_company());
+        assert_eq!(above.to_string(), "4301993079");
+    }
+
+    #[test]
+    fn from_u64_too_long() {
+        assert_eq!(
+            Kennitala::from_u64(10_000_000_000),
+            Err(KennitalaError::InvalidLength(11))
+        );
+        assert_eq!(
+            Kennitala::from_u64(31_100_029_200),
+            Err(KennitalaError::InvalidLength(11))
+        );
+        assert!(!matches!(
+            Kennitala::from_u64(9_999_999_999),
+            Err(KennitalaError::InvalidLength(_))
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn company_registration_date() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let kt = Kennitala::new("7112993009").unwrap();
+        assert_eq!(kt.get_day(), 31);
+        assert_eq!(kt.get_birthday(), date(1999, 12, 31));
+        assert_eq!(kt.registration_date(), Some(date(1999, 12, 31)));
+        assert_eq!(kt.date_of_birth(), None);
+        assert_eq!(kt.age_on(date(2020, 12, 30)), 20);
+        assert_eq!(kt.age_on(date(2020, 12, 31)), 21);
+
+        // The 41st of January is the 1st of January of a company.
+        let kt = Kennitala::with_corrected_checksum("4101002200").unwrap();
+        assert_eq!(kt.get_birthday(), date(2000, 1, 1));
+        assert_eq!(kt.registration_date(), Some(date(2000, 1, 1)));
+
+        let kt = Kennitala::new("3110002920").unwrap();
+        assert_eq!(kt.date_of_birth(), Some(date(2000, 10, 31)));
+        assert_eq!(kt.registration_date(), None);
+    }
+
+    #[test]
+    fn century_round_trip() {
+        for (kt_str, century, year) in &[
+            ("0101502998", 8, 1850),
+            ("1703715939", 9, 1971),
+            ("3110002920", 0, 2000),
+        ] {
+            let kt = Kennitala::new(kt_str).unwrap();
+            assert_eq!(kt.get_short_century(), *century);
+            assert_eq!(kt.get_year(), *year);
+            assert_eq!(kt.to_string(), *kt_str);
+            assert_eq!(Kennitala::from_packed(kt.as_u32()), Some(kt));
+            assert_eq!(Kennitala::from_u64(kt.to_decimal_u64()), Ok(kt));
+            let bits = (kt.as_u32() & CENTURY_MASK) >> CENTURY_OFFSET;
+            assert_eq!(u32::from(CENTURY_DIGITS[bits as usize]), *century);
+        }
+    }
+
+    #[test]
+    fn nineteenth_century() {
+        let kt = Kennitala::new("0101502998").unwrap();
+        assert_eq!(kt.get_year(), 1850);
+        assert_eq!(kt.get_short_year(), 50);
+        assert_eq!(kt.get_short_century(), 8);
+        assert_eq!(kt.to_string(), "0101502998");
+        #[cfg(feature = "chrono")]
+        assert_eq!(
+            kt.get_birthday(),
+            NaiveDate::from_ymd_opt(1850, 1, 1).unwrap()
+        );
+
+        // 1800 was not a leap year.
+        let kt = Kennitala::new("2902002298");
+        assert_eq!(kt, Err(KennitalaError::InvalidDay));
+        let kt = Kennitala::new("2902042248").unwrap();
+        assert_eq!(kt.get_year(), 1804);
+    }
+
+    #[test]
+    fn invalid_century() {
+        for century in &['1', '2', '3', '4', '5', '6', '7'] {
+            let kt = Kennitala::new(&format!("010150299{}", century));
+            assert_eq!(kt, Err(KennitalaError::InvalidCentury));
+        }
+    }
+
     #[test]
     fn max_u32() {
-        let kt = Kennitala::new(&std::u32::MAX.to_string());
-        assert!(kt.is_err());
+        let kt = Kennitala::new(&u32::MAX.to_string());
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
     }
 
     #[test]
     fn failed_fuzz_1() {
         let kt = Kennitala::new("3999999999");
-        assert!(kt.is_err());
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
     }
 
     #[test]
     fn failed_fuzz_2() {
         let kt = Kennitala::new("9999");
-        assert!(kt.is_err());
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(4)));
     }
 
     #[test]
     fn failed_fuzz_3() {
         let kt = Kennitala::new("01011413300");
-        assert!(kt.is_err());
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(11)));
     }
 }
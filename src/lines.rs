@@ -0,0 +1,149 @@
+use std::io::{self, BufRead};
+
+use crate::{ErrorCounts, Kennitala};
+
+/// Statistics from validating newline-delimited kennitölur with
+/// `validate_lines`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct ValidationStats {
+    valid: u64,
+    empty: u64,
+    error_counts: ErrorCounts,
+    offending_lines: Vec<u64>,
+}
+
+impl ValidationStats {
+    /// Get the number of lines with a valid kennitala.
+    pub fn valid(&self) -> u64 {
+        self.valid
+    }
+
+    /// Get the number of lines which are empty, or contain only whitespace.
+    pub fn empty(&self) -> u64 {
+        self.empty
+    }
+
+    /// Get the number of lines with an invalid kennitala.
+    pub fn invalid(&self) -> u64 {
+        self.error_counts.total()
+    }
+
+    /// Get the number of lines, including empty ones.
+    pub fn lines(&self) -> u64 {
+        self.valid + self.empty + self.invalid()
+    }
+
+    /// Get the number of invalid kennitölur of each `KennitalaError` variant.
+    pub fn error_counts(&self) -> &ErrorCounts {
+        &self.error_counts
+    }
+
+    /// Get the 1-based numbers of the first lines with an invalid kennitala,
+    /// up to the limit given to `validate_lines_recording`.
+    pub fn offending_lines(&self) -> &[u64] {
+        &self.offending_lines
+    }
+}
+
+/// Validate the newline-delimited kennitölur in the given reader, such as a
+/// multi-gigabyte export, and count the valid and invalid ones. Lines may end
+/// with either `\n` or `\r\n`, and leading and trailing whitespace is ignored.
+/// The lines need not be valid UTF-8, and are read into a single reused
+/// buffer.
+///
+/// ```
+/// let export = "3110002920\n1703715939\r\n\n3110002930\n";
+/// let stats = kennitolur::validate_lines(export.as_bytes()).unwrap();
+/// assert_eq!(stats.valid(), 2);
+/// assert_eq!(stats.empty(), 1);
+/// assert_eq!(stats.invalid(), 1);
+/// ```
+pub fn validate_lines<R: BufRead>(reader: R) -> io::Result<ValidationStats> {
+    validate_lines_recording(reader, 0)
+}
+
+/// Validate the newline-delimited kennitölur in the given reader like
+/// `validate_lines`, and record the numbers of the first `max_recorded` lines
+/// with an invalid kennitala.
+pub fn validate_lines_recording<R: BufRead>(
+    mut reader: R,
+    max_recorded: usize,
+) -> io::Result<ValidationStats> {
+    let mut stats = ValidationStats::default();
+    let mut line = Vec::new();
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(stats);
+        }
+        line_number += 1;
+
+        let kennitala = line.trim_ascii();
+        if kennitala.is_empty() {
+            stats.empty += 1;
+            continue;
+        }
+        match Kennitala::from_bytes(kennitala) {
+            Ok(_) => stats.valid += 1,
+            Err(e) => {
+                stats.error_counts.add(&e);
+                if stats.offending_lines.len() < max_recorded {
+                    stats.offending_lines.push(line_number);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KennitalaError;
+
+    const EXPORT: &[u8] = b"3110002920\n\
+1703715939\r\n\
+\n\
+3110002930\n\
+  7112993009  \n\
+31100O2920\n\
+\t\r\n\
+311000-2920\n\
+31100\xff2920\n\
+0101012980";
+
+    #[test]
+    fn mixed() {
+        let stats = validate_lines(EXPORT).unwrap();
+        assert_eq!(stats.lines(), 10);
+        assert_eq!(stats.valid(), 4);
+        assert_eq!(stats.empty(), 2);
+        assert_eq!(stats.invalid(), 4);
+        let counts = stats.error_counts();
+        assert_eq!(counts.get(&KennitalaError::InvalidChecksum), 1);
+        assert_eq!(
+            counts.get(&KennitalaError::InvalidNumber {
+                index: 0,
+                found: '0'
+            }),
+            3
+        );
+        assert!(stats.offending_lines().is_empty());
+    }
+
+    #[test]
+    fn offending_lines() {
+        let stats = validate_lines_recording(EXPORT, 10).unwrap();
+        assert_eq!(stats.offending_lines(), &[4, 6, 8, 9]);
+        let stats = validate_lines_recording(EXPORT, 2).unwrap();
+        assert_eq!(stats.offending_lines(), &[4, 6]);
+        assert_eq!(stats.invalid(), 4);
+    }
+
+    #[test]
+    fn empty_input() {
+        let stats = validate_lines(&b""[..]).unwrap();
+        assert_eq!(stats, ValidationStats::default());
+        assert_eq!(stats.lines(), 0);
+    }
+}
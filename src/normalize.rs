@@ -0,0 +1,116 @@
+use crate::{Kennitala, KennitalaError, ParseOptions};
+
+/// Convert the given kennitala to its canonical form of 10 ASCII digits, as
+/// returned by `Kennitala::to_string`. This accepts the shapes commonly typed
+/// by users:
+/// - leading and trailing whitespace, such as `" 3110002920\n"`,
+/// - a hyphen or a space between the sixth and seventh digit, such as
+///   `"311000-2920"` or `"311000 2920"`,
+/// - full-width digits, hyphens and spaces, as typed on some mobile keyboards,
+///   such as `"３１１０００－２９２０"`.
+///
+/// The positions in `KennitalaError::InvalidNumber` errors are byte offsets
+/// into the given input.
+/// ```
+/// assert_eq!(kennitolur::normalize(" 311000-2920 ").unwrap(), "3110002920");
+/// assert_eq!(kennitolur::normalize("３１１０００ ２９２０").unwrap(), "3110002920");
+/// ```
+pub fn normalize(kennitala: &str) -> Result<String, KennitalaError> {
+    let halfwidth: String = kennitala.chars().map(to_halfwidth).collect();
+    let options = ParseOptions::strict().trim_whitespace(true);
+    let kt = Kennitala::parse_with(&halfwidth, &options.allow_separator('-'))
+        .or_else(|e| {
+            Kennitala::parse_with(&halfwidth, &options.allow_separator(' ')).map_err(|_| e)
+        })
+        .map_err(|e| locate_in_input(e, kennitala, &halfwidth))?;
+    Ok(kt.to_string())
+}
+
+/// Convert full-width digits, hyphens and spaces to their ASCII counterparts.
+fn to_halfwidth(c: char) -> char {
+    match c {
+        '\u{ff10}'..='\u{ff19}' => char::from(b'0' + (u32::from(c) - 0xff10) as u8),
+        '\u{ff0d}' => '-',
+        '\u{3000}' => ' ',
+        c => c,
+    }
+}
+
+/// Convert the position of an `InvalidNumber` error in the half-width form of
+/// the input to a position in the input itself.
+fn locate_in_input(e: KennitalaError, input: &str, halfwidth: &str) -> KennitalaError {
+    match e {
+        KennitalaError::InvalidNumber { index, found } => halfwidth
+            .char_indices()
+            .zip(input.char_indices())
+            .find(|((halfwidth_index, _), _)| *halfwidth_index == index)
+            .map(|(_, (index, found))| KennitalaError::InvalidNumber { index, found })
+            .unwrap_or(KennitalaError::InvalidNumber { index, found }),
+        e => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_kennitala() {
+        for input in &[
+            "3110002920",
+            "311000-2920",
+            "311000 2920",
+            " 311000-2920\n",
+            "\t3110002920 ",
+            "３１１０００２９２０",
+            "３１１０００－２９２０",
+            "３１１０００\u{3000}２９２０",
+            "311000－2920",
+        ] {
+            let normalized = normalize(input).unwrap();
+            assert_eq!(normalized, "3110002920", "{:?}", input);
+            assert_eq!(Kennitala::new(&normalized).unwrap().to_string(), normalized);
+        }
+    }
+
+    #[test]
+    fn companies() {
+        assert_eq!(normalize("711299-3009").unwrap(), "7112993009");
+    }
+
+    #[test]
+    fn invalid() {
+        let kt = normalize("311000-2921");
+        assert_eq!(kt, Err(KennitalaError::InvalidCentury));
+        let kt = normalize("3110002930");
+        assert_eq!(kt, Err(KennitalaError::InvalidChecksum));
+        let kt = normalize("31100-02920");
+        assert!(matches!(kt, Err(KennitalaError::InvalidNumber { .. })));
+        let kt = normalize("311000--2920");
+        assert!(matches!(kt, Err(KennitalaError::InvalidNumber { .. })));
+        let kt = normalize("311000-292");
+        assert!(matches!(kt, Err(KennitalaError::InvalidNumber { .. })));
+        let kt = normalize("");
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn invalid_number_position() {
+        let kt = normalize("３１１０００－２９x０");
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 27,
+                found: 'x'
+            })
+        );
+        let kt = normalize(" 3110a02920");
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 5,
+                found: 'a'
+            })
+        );
+    }
+}
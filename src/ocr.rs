@@ -0,0 +1,189 @@
+use crate::{Kennitala, KennitalaError, ParseOptions};
+
+/// A kennitala parsed with `Kennitala::parse_with_corrections`, along with the
+/// characters which were corrected to get it.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Corrected {
+    kennitala: Kennitala,
+    positions: Vec<usize>,
+}
+
+impl Corrected {
+    /// Get the parsed kennitala.
+    #[inline]
+    pub fn kennitala(&self) -> Kennitala {
+        self.kennitala
+    }
+
+    /// Get the byte offsets into the input of the characters which were
+    /// corrected, in ascending order. This is empty if the input was valid as
+    /// given.
+    #[inline]
+    pub fn corrected_positions(&self) -> &[usize] {
+        &self.positions
+    }
+
+    /// Whether any characters were corrected.
+    #[inline]
+    pub fn is_corrected(&self) -> bool {
+        !self.positions.is_empty()
+    }
+}
+
+impl Kennitala {
+    /// Create new kennitala object from the given string, parsed according to
+    /// the given options, and report which characters were corrected if
+    /// `ParseOptions::ocr_corrections` is enabled. The following characters
+    /// are corrected:
+    ///
+    /// | Misread as             | Digit |
+    /// |------------------------|-------|
+    /// | `O`, `o`, `D`, `Q`     | `0`   |
+    /// | `I`, `l`, `i`, `\|`    | `1`   |
+    /// | `Z`, `z`               | `2`   |
+    /// | `S`, `s`               | `5`   |
+    /// | `G`, `b`               | `6`   |
+    /// | `B`                    | `8`   |
+    /// | `g`, `q`               | `9`   |
+    ///
+    /// If the corrected kennitala is invalid as well, the error for the input
+    /// as given is returned, rather than guessing further.
+    /// ```
+    /// use kennitolur::{Kennitala, ParseOptions};
+    ///
+    /// let options = ParseOptions::lenient().ocr_corrections(true);
+    /// let corrected = Kennitala::parse_with_corrections("3II0OO-2920", &options).unwrap();
+    /// assert_eq!(corrected.kennitala().to_string(), "3110002920");
+    /// assert_eq!(corrected.corrected_positions(), &[1, 2, 4, 5]);
+    /// ```
+    pub fn parse_with_corrections(
+        kennitala: &str,
+        options: &ParseOptions,
+    ) -> Result<Corrected, KennitalaError> {
+        let error = match Kennitala::parse_uncorrected(kennitala, options) {
+            Ok(kennitala) => {
+                return Ok(Corrected {
+                    kennitala,
+                    positions: Vec::new(),
+                })
+            }
+            Err(e) => e,
+        };
+        if !options.corrects_ocr() {
+            return Err(error);
+        }
+
+        let mut positions = Vec::new();
+        let corrected: String = kennitala
+            .char_indices()
+            .map(|(i, c)| match ocr_digit(c) {
+                Some(digit) => {
+                    positions.push(i);
+                    digit
+                }
+                None => c,
+            })
+            .collect();
+        if positions.is_empty() {
+            return Err(error);
+        }
+        match Kennitala::parse_uncorrected(&corrected, options) {
+            Ok(kennitala) => Ok(Corrected {
+                kennitala,
+                positions,
+            }),
+            Err(_) => Err(error),
+        }
+    }
+}
+
+/// Get the digit which the given character is commonly misread for by optical
+/// character recognition, if any.
+fn ocr_digit(c: char) -> Option<char> {
+    match c {
+        'O' | 'o' | 'D' | 'Q' => Some('0'),
+        'I' | 'l' | 'i' | '|' => Some('1'),
+        'Z' | 'z' => Some('2'),
+        'S' | 's' => Some('5'),
+        'G' | 'b' => Some('6'),
+        'B' => Some('8'),
+        'g' | 'q' => Some('9'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ocr() -> ParseOptions {
+        ParseOptions::lenient().ocr_corrections(true)
+    }
+
+    #[test]
+    fn corrections() {
+        for (input, expected, positions) in &[
+            ("3II0OO-2920", "3110002920", &[1, 2, 4, 5][..]),
+            ("l7O37l-S939", "1703715939", &[0, 2, 5, 7][..]),
+            ("  O3112O3l49 ", "0311203149", &[2, 7, 9][..]),
+            ("7ll299-3OOg", "7112993009", &[1, 2, 8, 9, 10][..]),
+            ("29O2OO2Z9O", "2902002290", &[2, 4, 5, 7, 9][..]),
+            ("0101O12gBO", "0101012980", &[4, 7, 8, 9][..]),
+        ] {
+            let corrected = Kennitala::parse_with_corrections(input, &ocr()).unwrap();
+            assert_eq!(corrected.kennitala().to_string(), *expected, "{:?}", input);
+            assert_eq!(corrected.corrected_positions(), *positions, "{:?}", input);
+            assert!(corrected.is_corrected());
+            assert_eq!(
+                Kennitala::parse_with(input, &ocr()).unwrap(),
+                corrected.kennitala()
+            );
+        }
+    }
+
+    #[test]
+    fn no_corrections_needed() {
+        let corrected = Kennitala::parse_with_corrections("311000-2920", &ocr()).unwrap();
+        assert_eq!(corrected.kennitala().to_string(), "3110002920");
+        assert!(!corrected.is_corrected());
+        assert!(corrected.corrected_positions().is_empty());
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let kt = Kennitala::parse_with_corrections("3II0OO-2920", &ParseOptions::lenient());
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 1,
+                found: 'I'
+            })
+        );
+    }
+
+    #[test]
+    fn original_error_if_correction_is_invalid() {
+        // Correcting `S` to `5` gives an invalid checksum.
+        let kt = Kennitala::parse_with_corrections("3110002S20", &ocr());
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 7,
+                found: 'S'
+            })
+        );
+        // Characters outside the table are not guessed.
+        let kt = Kennitala::parse_with_corrections("3I10002?20", &ocr());
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 1,
+                found: 'I'
+            })
+        );
+        let kt = Kennitala::parse_with_corrections("3110002921", &ocr());
+        assert_eq!(kt, Err(KennitalaError::InvalidCentury));
+        let kt = Kennitala::parse_with_corrections("Kt. 3110002920", &ocr());
+        assert!(matches!(kt, Err(KennitalaError::InvalidNumber { .. })));
+    }
+}
@@ -0,0 +1,363 @@
+use crate::{ascii_to_array, Kennitala, KennitalaError};
+
+/// Options controlling how strictly `Kennitala::parse_with` parses its input.
+///
+/// The options are set through a builder:
+/// ```
+/// use kennitolur::{Kennitala, ParseOptions};
+///
+/// let options = ParseOptions::strict()
+///     .allow_separator('-')
+///     .trim_whitespace(true);
+/// assert!(Kennitala::parse_with(" 311000-2920\n", &options).is_ok());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ParseOptions {
+    separator: Option<char>,
+    trim_whitespace: bool,
+    allow_company: bool,
+    #[cfg(feature = "std")]
+    ocr_corrections: bool,
+}
+
+impl ParseOptions {
+    /// Accept exactly what `Kennitala::new` accepts: 10 digits, with nothing
+    /// before, after, or between them.
+    pub fn strict() -> Self {
+        ParseOptions {
+            separator: None,
+            trim_whitespace: false,
+            allow_company: true,
+            #[cfg(feature = "std")]
+            ocr_corrections: false,
+        }
+    }
+
+    /// Accept a hyphen between the sixth and seventh digit, and ignore leading
+    /// and trailing whitespace.
+    pub fn lenient() -> Self {
+        ParseOptions::strict()
+            .allow_separator('-')
+            .trim_whitespace(true)
+    }
+
+    /// Accept the given separator between the sixth and seventh digit, as in
+    /// `311000-2920`. The separator is optional, and is rejected with
+    /// `KennitalaError::InvalidNumber` in any other position.
+    pub fn allow_separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// Whether to ignore leading and trailing ASCII whitespace, such as a
+    /// trailing newline. Whitespace within the kennitala is always rejected.
+    pub fn trim_whitespace(mut self, trim_whitespace: bool) -> Self {
+        self.trim_whitespace = trim_whitespace;
+        self
+    }
+
+    /// Whether to accept kennitölur belonging to companies. If not, these are
+    /// rejected with `KennitalaError::InvalidDay`, since their day is offset
+    /// by 40.
+    pub fn allow_company(mut self, allow_company: bool) -> Self {
+        self.allow_company = allow_company;
+        self
+    }
+
+    /// Whether to correct characters which optical character recognition
+    /// commonly misreads for digits, such as `O` for `0` and `l` for `1`. The
+    /// corrections are only made if the input is invalid without them, and are
+    /// only accepted if the corrected kennitala is valid. Use
+    /// `Kennitala::parse_with_corrections` to find out which characters were
+    /// corrected.
+    #[cfg(feature = "std")]
+    pub fn ocr_corrections(mut self, ocr_corrections: bool) -> Self {
+        self.ocr_corrections = ocr_corrections;
+        self
+    }
+
+    /// Whether characters misread by optical character recognition are
+    /// corrected.
+    #[cfg(feature = "std")]
+    pub(crate) fn corrects_ocr(&self) -> bool {
+        self.ocr_corrections
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::strict()
+    }
+}
+
+impl Kennitala {
+    /// Create new kennitala object from the given string, parsed according to
+    /// the given options. Validation is done beforehand.
+    pub fn parse_with(kennitala: &str, options: &ParseOptions) -> Result<Self, KennitalaError> {
+        #[cfg(feature = "std")]
+        {
+            if options.corrects_ocr() {
+                return Kennitala::parse_with_corrections(kennitala, options)
+                    .map(|corrected| corrected.kennitala());
+            }
+        }
+        Kennitala::parse_uncorrected(kennitala, options)
+    }
+
+    /// Parse the given string according to the given options, without any
+    /// OCR corrections.
+    pub(crate) fn parse_uncorrected(
+        kennitala: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, KennitalaError> {
+        let input = kennitala;
+        // The kennitala itself, and its byte offset within the input so that
+        // errors point into the input.
+        let (kennitala, offset) = if options.trim_whitespace {
+            let trimmed = input.trim_start_matches(|c: char| c.is_ascii_whitespace());
+            (
+                trimmed.trim_end_matches(|c: char| c.is_ascii_whitespace()),
+                input.len() - trimmed.len(),
+            )
+        } else {
+            (input, 0)
+        };
+
+        let without_separator = options.separator.and_then(|separator| {
+            remove_separator(kennitala, separator).map(|bytes| (bytes, separator))
+        });
+        let kt = match without_separator {
+            Some((bytes, separator)) => {
+                let digits = ascii_to_array(&bytes).map_err(|e| match e {
+                    KennitalaError::InvalidNumber { index, found } if index >= 6 => {
+                        KennitalaError::InvalidNumber {
+                            index: index + separator.len_utf8(),
+                            found,
+                        }
+                    }
+                    e => e,
+                });
+                Kennitala::from_slice(&digits.map_err(|e| e.locate_in(input, offset))?)?
+            }
+            None => Kennitala::new(kennitala).map_err(|e| e.locate_in(input, offset))?,
+        };
+
+        if kt.is_company() && !options.allow_company {
+            return Err(KennitalaError::InvalidDay);
+        }
+        Ok(kt)
+    }
+
+    /// Create new kennitala object from user input, ignoring ASCII whitespace
+    /// anywhere in it, as in `" 31 10 00 2920 "`, and accepting a single hyphen
+    /// between the sixth and seventh digit. Any other character, or a hyphen
+    /// elsewhere, is rejected with `KennitalaError::InvalidNumber`, and
+    /// `KennitalaError::InvalidLength` holds the number of digits found.
+    /// Validation is done beforehand.
+    pub fn parse_loose(kennitala: &str) -> Result<Self, KennitalaError> {
+        let mut digits = [0; 10];
+        let mut length = 0;
+        let mut seen_hyphen = false;
+        for (index, c) in kennitala.char_indices() {
+            match c {
+                '0'..='9' => {
+                    if let Some(digit) = digits.get_mut(length) {
+                        *digit = c as u8 - b'0';
+                    }
+                    length += 1;
+                }
+                '-' if length == 6 && !seen_hyphen => seen_hyphen = true,
+                c if c.is_ascii_whitespace() => {}
+                found => return Err(KennitalaError::InvalidNumber { index, found }),
+            }
+        }
+        if length != 10 {
+            return Err(KennitalaError::InvalidLength(length));
+        }
+        Kennitala::from_slice(&digits)
+    }
+}
+
+/// Remove the separator between the sixth and seventh character of the given
+/// kennitala, if there is one there.
+fn remove_separator(kennitala: &str, separator: char) -> Option<[u8; 10]> {
+    let date = kennitala.get(..6)?;
+    let rest = kennitala.get(6..)?.strip_prefix(separator)?;
+    if rest.len() != 4 {
+        return None;
+    }
+
+    let mut without_separator = [0; 10];
+    without_separator[..6].copy_from_slice(date.as_bytes());
+    without_separator[6..].copy_from_slice(rest.as_bytes());
+    Some(without_separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_combination() {
+        // Each input, along with whether it needs a separator to be allowed,
+        // whitespace to be trimmed, and companies to be allowed, respectively.
+        let table = [
+            ("3110002920", false, false, false),
+            ("311000-2920", true, false, false),
+            (" 3110002920\n", false, true, false),
+            ("\t311000-2920 ", true, true, false),
+            ("7112993009", false, false, true),
+            ("711299-3009", true, false, true),
+            ("7112993009\r\n", false, true, true),
+            (" 711299-3009", true, true, true),
+        ];
+        for &separator in &[false, true] {
+            for &trim_whitespace in &[false, true] {
+                for &allow_company in &[false, true] {
+                    let mut options = ParseOptions::strict()
+                        .trim_whitespace(trim_whitespace)
+                        .allow_company(allow_company);
+                    if separator {
+                        options = options.allow_separator('-');
+                    }
+                    for &(input, needs_separator, needs_trim, is_company) in &table {
+                        let expected = (separator || !needs_separator)
+                            && (trim_whitespace || !needs_trim)
+                            && (allow_company || !is_company);
+                        let kt = Kennitala::parse_with(input, &options);
+                        assert_eq!(kt.is_ok(), expected, "{:?} with {:?}", input, options);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn always_rejected() {
+        let options = ParseOptions::lenient();
+        for input in &[
+            "31100-02920",
+            "311000--2920",
+            "311000 2920",
+            "311000-292",
+            "311000-29200",
+            "3110002920-",
+            "31 10002920",
+        ] {
+            let kt = Kennitala::parse_with(input, &options);
+            assert!(
+                matches!(kt, Err(KennitalaError::InvalidNumber { .. })),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let kt = Kennitala::parse_with("7112993009", &ParseOptions::strict().allow_company(false));
+        assert_eq!(kt, Err(KennitalaError::InvalidDay));
+        let kt = Kennitala::parse_with(" 311000292 ", &ParseOptions::lenient());
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(9)));
+        let kt = Kennitala::parse_with("311000-2921", &ParseOptions::lenient());
+        assert_eq!(kt, Err(KennitalaError::InvalidCentury));
+    }
+
+    #[test]
+    fn invalid_number_position() {
+        let options = ParseOptions::lenient();
+        for (input, position, character) in &[
+            ("311000-29x0", 9, 'x'),
+            ("31100x-2920", 5, 'x'),
+            ("  311000-29x0", 11, 'x'),
+            (" 31 10002920", 3, ' '),
+            ("3110\u{e9}0-2920", 4, '\u{e9}'),
+        ] {
+            match Kennitala::parse_with(input, &options) {
+                Err(KennitalaError::InvalidNumber { index, found }) => {
+                    assert_eq!((index, found), (*position, *character), "{:?}", input)
+                }
+                kt => panic!("{:?} gave {:?}", input, kt),
+            }
+        }
+        let options = ParseOptions::strict().allow_separator('–');
+        let kt = Kennitala::parse_with("311000–29x0", &options);
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 11,
+                found: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn other_separators() {
+        let kt = Kennitala::parse_with("311000 2920", &ParseOptions::strict().allow_separator(' '));
+        assert_eq!(kt.unwrap(), Kennitala::new("3110002920").unwrap());
+        let kt = Kennitala::parse_with("311000–2920", &ParseOptions::strict().allow_separator('–'));
+        assert_eq!(kt.unwrap(), Kennitala::new("3110002920").unwrap());
+        let kt = Kennitala::parse_with("311000-2920", &ParseOptions::strict().allow_separator('–'));
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 6,
+                found: '-'
+            })
+        );
+    }
+
+    #[test]
+    fn loose() {
+        let expected = Kennitala::new("3110002920").unwrap();
+        for input in &[
+            "3110002920",
+            "311000-2920",
+            "31 10 00 2920",
+            "  3110002920\n",
+            "\t311000 - 2920 ",
+            "31 10 00-29 20",
+        ] {
+            assert_eq!(Kennitala::parse_loose(input), Ok(expected), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn loose_rejected() {
+        for (input, index, found) in &[
+            ("311000--2920", 7, '-'),
+            ("31100-02920", 5, '-'),
+            ("-3110002920", 0, '-'),
+            ("3110002920-", 10, '-'),
+            ("311000-29-20", 9, '-'),
+            ("311000a2920", 6, 'a'),
+            ("31 10 00 2920 kr", 14, 'k'),
+            ("311000\u{a0}2920", 6, '\u{a0}'),
+        ] {
+            let kt = Kennitala::parse_loose(input);
+            assert_eq!(
+                kt,
+                Err(KennitalaError::InvalidNumber {
+                    index: *index,
+                    found: *found
+                }),
+                "{:?}",
+                input
+            );
+        }
+        let kt = Kennitala::parse_loose(" 31 10 00 292 ");
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(9)));
+        let kt = Kennitala::parse_loose("31 10 00 29 20 1");
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(11)));
+        let kt = Kennitala::parse_loose("   ");
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(0)));
+        let kt = Kennitala::parse_loose("31 10 00 2921");
+        assert_eq!(kt, Err(KennitalaError::InvalidCentury));
+    }
+
+    #[test]
+    fn presets() {
+        assert_eq!(ParseOptions::default(), ParseOptions::strict());
+        assert_ne!(ParseOptions::lenient(), ParseOptions::strict());
+    }
+}
@@ -0,0 +1,146 @@
+use std::convert::TryFrom;
+
+use crate::{ascii_to_array, validate_slice, ErrorCounts, KennitalaError};
+
+/// The result of validating packed records with `validate_packed`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct PackedReport {
+    records: usize,
+    invalid: Vec<usize>,
+    error_counts: ErrorCounts,
+    remainder: usize,
+}
+
+impl PackedReport {
+    /// Get the number of whole records.
+    pub fn records(&self) -> usize {
+        self.records
+    }
+
+    /// Get the number of records with a valid kennitala.
+    pub fn valid(&self) -> usize {
+        self.records - self.invalid.len()
+    }
+
+    /// Get the 0-based indices of the records with an invalid kennitala, in
+    /// ascending order.
+    pub fn invalid(&self) -> &[usize] {
+        &self.invalid
+    }
+
+    /// Get the number of invalid kennitölur of each `KennitalaError` variant.
+    pub fn error_counts(&self) -> &ErrorCounts {
+        &self.error_counts
+    }
+
+    /// Get the number of bytes at the end of the data which do not make up a
+    /// whole record, and were not validated.
+    pub fn remainder(&self) -> usize {
+        self.remainder
+    }
+}
+
+/// Validate the kennitölur in the given data, which consists of contiguous
+/// records of `record_len` bytes with no separators, each containing 10 ASCII
+/// digits at `offset`. The data can be a memory-mapped file.
+///
+/// ```
+/// let data = b"3110002920|1703715939|3110002930|";
+/// let report = kennitolur::validate_packed(data, 11, 0);
+/// assert_eq!(report.records(), 3);
+/// assert_eq!(report.invalid(), &[2]);
+/// ```
+///
+/// # Panics
+/// Panics if a kennitala at `offset` would not fit within a record.
+pub fn validate_packed(data: &[u8], record_len: usize, offset: usize) -> PackedReport {
+    assert!(
+        offset.checked_add(10).is_some_and(|end| end <= record_len),
+        "the kennitala at offset {} does not fit in a record of {} bytes",
+        offset,
+        record_len
+    );
+
+    let records = data.chunks_exact(record_len);
+    let mut report = PackedReport {
+        records: records.len(),
+        remainder: records.remainder().len(),
+        ..PackedReport::default()
+    };
+    for (i, record) in records.enumerate() {
+        let kennitala = <&[u8; 10]>::try_from(&record[offset..offset + 10])
+            .expect("the record has room for the kennitala");
+        if let Err(e) = validate_ascii(kennitala) {
+            report.error_counts.add(&e);
+            report.invalid.push(i);
+        }
+    }
+    report
+}
+
+/// Validate the given ASCII kennitala, without constructing a `Kennitala`.
+#[inline]
+fn validate_ascii(kennitala: &[u8; 10]) -> Result<(), KennitalaError> {
+    ascii_to_array(kennitala).and_then(|digits| validate_slice(&digits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Kennitala;
+
+    #[test]
+    fn without_separators() {
+        let data = b"31100029201703715939311000293071129930093110002";
+        let report = validate_packed(data, 10, 0);
+        assert_eq!(report.records(), 4);
+        assert_eq!(report.valid(), 3);
+        assert_eq!(report.invalid(), &[2]);
+        assert_eq!(
+            report.error_counts().get(&KennitalaError::InvalidChecksum),
+            1
+        );
+        assert_eq!(report.remainder(), 7);
+    }
+
+    #[test]
+    fn with_other_fields() {
+        let data = b"\x00\x013110002920\xff\x00\x0231100O2920\xfe\x00\x031703715939\xfd";
+        let report = validate_packed(data, 13, 2);
+        assert_eq!(report.records(), 3);
+        assert_eq!(report.invalid(), &[1]);
+        assert_eq!(report.remainder(), 0);
+    }
+
+    #[test]
+    fn agrees_with_new() {
+        for kt in &[
+            "3110002920",
+            "3110002930",
+            "3213002920",
+            "31100O2920",
+            "3110\u{e9}2920",
+            "7112993009",
+            "4001002090",
+        ] {
+            let report = validate_packed(kt.as_bytes(), kt.len(), 0);
+            let expected = Kennitala::from_bytes(kt.as_bytes()).map(|_| ());
+            match expected {
+                Ok(()) => assert!(report.invalid().is_empty(), "{:?}", kt),
+                Err(e) => assert_eq!(report.error_counts().get(&e), 1, "{:?}", kt),
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let report = validate_packed(b"", 10, 0);
+        assert_eq!(report, PackedReport::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn kennitala_does_not_fit() {
+        validate_packed(b"3110002920", 10, 1);
+    }
+}
@@ -0,0 +1,90 @@
+//! Storage of kennitölur in PostgreSQL through `postgres-types`, as used by
+//! `tokio-postgres` and `postgres`. A kennitala is stored as its canonical 10
+//! digit string in a `TEXT`, `VARCHAR` or `CHAR` column. Reading validates the
+//! kennitala, after removing the trailing spaces which a `CHAR` column wider
+//! than 10 characters pads it with.
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::Kennitala;
+
+impl ToSql for Kennitala {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        <&str as ToSql>::to_sql(&&*self.to_fixed(), ty, out)
+    }
+
+    accepts!(TEXT, VARCHAR, BPCHAR);
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Kennitala {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let kennitala = <&str as FromSql<'_>>::from_sql(ty, raw)?;
+        Ok(Kennitala::new(kennitala.trim_end_matches(' '))?)
+    }
+
+    accepts!(TEXT, VARCHAR, BPCHAR);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KennitalaError;
+
+    #[test]
+    fn to_sql() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        for ty in &[Type::TEXT, Type::VARCHAR, Type::BPCHAR] {
+            let mut out = BytesMut::new();
+            let is_null = kt.to_sql_checked(ty, &mut out).unwrap();
+            assert!(matches!(is_null, IsNull::No));
+            assert_eq!(&out[..], b"0311203149");
+        }
+        let mut out = BytesMut::new();
+        assert!(kt.to_sql_checked(&Type::INT8, &mut out).is_err());
+    }
+
+    #[test]
+    fn from_sql() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        for ty in &[Type::TEXT, Type::VARCHAR, Type::BPCHAR] {
+            assert_eq!(Kennitala::from_sql(ty, b"0311203149").unwrap(), kt);
+        }
+        assert_eq!(
+            Kennitala::from_sql(&Type::BPCHAR, b"0311203149  ").unwrap(),
+            kt
+        );
+        assert!(!<Kennitala as FromSql<'_>>::accepts(&Type::INT8));
+    }
+
+    #[test]
+    fn from_sql_invalid() {
+        let e = Kennitala::from_sql(&Type::TEXT, b"3110002921").unwrap_err();
+        assert_eq!(
+            e.downcast_ref::<KennitalaError>(),
+            Some(&KennitalaError::InvalidCentury)
+        );
+        let e = Kennitala::from_sql(&Type::TEXT, b" 3110002920").unwrap_err();
+        assert!(e.downcast_ref::<KennitalaError>().is_some());
+        assert!(Kennitala::from_sql(&Type::TEXT, b"\xff").is_err());
+    }
+
+    #[test]
+    fn null() {
+        let kt = Option::<Kennitala>::from_sql_null(&Type::TEXT).unwrap();
+        assert_eq!(kt, None);
+        assert!(Kennitala::from_sql_null(&Type::TEXT).is_err());
+        let mut out = BytesMut::new();
+        let is_null = None::<Kennitala>
+            .to_sql_checked(&Type::TEXT, &mut out)
+            .unwrap();
+        assert!(matches!(is_null, IsNull::Yes));
+    }
+}
@@ -0,0 +1,328 @@
+use crate::dates::days_in_month;
+use crate::{calculate_checksum_digit, Kennitala, KennitalaError, COMPANY_DAY_OFFSET};
+
+/// The status of a kennitala which is being typed in, as returned by
+/// `Kennitala::validate_prefix`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PrefixStatus {
+    /// The prefix can still become a valid kennitala.
+    Incomplete {
+        /// The digits which can come next, such that the prefix can still
+        /// become a valid kennitala.
+        next: DigitSet,
+    },
+    /// The prefix is a whole valid kennitala.
+    Complete(Kennitala),
+}
+
+/// A set of the digits from 0 to 9.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct DigitSet {
+    bits: u16,
+}
+
+impl DigitSet {
+    /// Whether the given digit is in the set.
+    #[inline]
+    pub fn contains(&self, digit: u8) -> bool {
+        digit <= 9 && (self.bits >> digit) & 1 == 1
+    }
+
+    /// Get the number of digits in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Whether the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Iterate over the digits in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..10).filter(move |digit| self.contains(*digit))
+    }
+
+    fn insert(&mut self, digit: u8) {
+        self.bits |= 1 << digit;
+    }
+}
+
+impl Kennitala {
+    /// Check whether the given prefix of a kennitala, such as the digits typed
+    /// into a form so far, can still become a valid kennitala. Each digit is
+    /// checked as soon as possible: the day and month as soon as they are
+    /// typed, taking the month and leap years into account, the random digits
+    /// once the seventh digit is typed, and the checksum once the eighth and
+    /// ninth are. The century digit and the whole kennitala are checked once
+    /// all 10 digits are there, as by `Kennitala::new`.
+    ///
+    /// ```
+    /// use kennitolur::{Kennitala, KennitalaError, PrefixStatus};
+    ///
+    /// match Kennitala::validate_prefix("3").unwrap() {
+    ///     PrefixStatus::Incomplete { next } => {
+    ///         assert_eq!(next.iter().collect::<Vec<_>>(), [0, 1]);
+    ///     }
+    ///     PrefixStatus::Complete(_) => unreachable!(),
+    /// }
+    /// assert_eq!(Kennitala::validate_prefix("3102"), Err(KennitalaError::InvalidDay));
+    /// ```
+    pub fn validate_prefix(prefix: &str) -> Result<PrefixStatus, KennitalaError> {
+        let mut digits = [0; 10];
+        let mut length = 0;
+        for (index, c) in prefix.char_indices() {
+            let digit = c
+                .to_digit(10)
+                .ok_or(KennitalaError::InvalidNumber { index, found: c })?;
+            if let Some(d) = digits.get_mut(length) {
+                *d = digit as u8;
+            }
+            length += 1;
+        }
+        if length > 10 {
+            return Err(KennitalaError::InvalidLength(length));
+        }
+        if length == 10 {
+            return Kennitala::from_digits(digits).map(PrefixStatus::Complete);
+        }
+
+        check_prefix(&digits[..length])?;
+        let mut next = DigitSet::default();
+        for digit in 0..10 {
+            digits[length] = digit;
+            let is_feasible = if length == 9 {
+                Kennitala::from_digits(digits).is_ok()
+            } else {
+                check_prefix(&digits[..=length]).is_ok()
+            };
+            if is_feasible {
+                next.insert(digit);
+            }
+        }
+        debug_assert!(!next.is_empty());
+        Ok(PrefixStatus::Incomplete { next })
+    }
+}
+
+/// Check whether the given digits, of which there are fewer than 10, can be
+/// the start of a valid kennitala.
+fn check_prefix(digits: &[u8]) -> Result<(), KennitalaError> {
+    debug_assert!(digits.len() < 10);
+
+    let days = (1..=31).chain((1 + COMPANY_DAY_OFFSET)..=(31 + COMPANY_DAY_OFFSET));
+    if !days.clone().any(|day| matches_at(digits, 0, day)) {
+        return Err(KennitalaError::InvalidDay);
+    }
+    if !(1..=12).any(|month| matches_at(digits, 2, month)) {
+        return Err(KennitalaError::InvalidMonth);
+    }
+    let is_valid_date = days.filter(|day| matches_at(digits, 0, *day)).any(|day| {
+        let day = if day > COMPANY_DAY_OFFSET {
+            day - COMPANY_DAY_OFFSET
+        } else {
+            day
+        };
+        (1..=12)
+            .filter(|month| matches_at(digits, 2, *month))
+            .any(|month| is_valid_day(digits, day, month))
+    });
+    if !is_valid_date {
+        return Err(KennitalaError::InvalidDay);
+    }
+
+    if digits.len() > 6 && digits[6] < 2 {
+        return Err(KennitalaError::InvalidRandomDigits);
+    }
+    if digits.len() >= 8 {
+        let mut kennitala = [0; 10];
+        kennitala[..digits.len()].copy_from_slice(digits);
+        let checksum_digit = calculate_checksum_digit(&kennitala);
+        if checksum_digit > 9 || (digits.len() == 9 && digits[8] != checksum_digit) {
+            return Err(KennitalaError::InvalidChecksum);
+        }
+    }
+    Ok(())
+}
+
+/// Whether the given day is valid in the given month for some year matching
+/// the given digits, in any century.
+fn is_valid_day(digits: &[u8], day: u32, month: u32) -> bool {
+    // Every day but the 29th of February is valid in any year.
+    let fits = |year| days_in_month(month, year).is_some_and(|days| day <= u32::from(days));
+    if fits(1901) {
+        return true;
+    }
+    (0..100)
+        .filter(|short_year| matches_at(digits, 4, *short_year))
+        .any(|short_year| {
+            [1800, 1900, 2000]
+                .iter()
+                .any(|century| fits(century + short_year))
+        })
+}
+
+/// Whether the given two digit number matches the digits at the given position,
+/// as far as there are any.
+fn matches_at(digits: &[u8], position: usize, number: u32) -> bool {
+    let tens = (number / 10) as u8;
+    let ones = (number % 10) as u8;
+    digits.get(position).is_none_or(|d| *d == tens)
+        && digits.get(position + 1).is_none_or(|d| *d == ones)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next_digits(prefix: &str) -> Vec<u8> {
+        match Kennitala::validate_prefix(prefix) {
+            Ok(PrefixStatus::Incomplete { next }) => next.iter().collect(),
+            status => panic!("{:?} gave {:?}", prefix, status),
+        }
+    }
+
+    #[test]
+    fn day() {
+        assert_eq!(next_digits(""), [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(next_digits("0"), [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(next_digits("3"), [0, 1]);
+        assert_eq!(next_digits("4"), [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(next_digits("7"), [0, 1]);
+        assert_eq!(
+            Kennitala::validate_prefix("00"),
+            Err(KennitalaError::InvalidDay)
+        );
+        assert_eq!(
+            Kennitala::validate_prefix("32"),
+            Err(KennitalaError::InvalidDay)
+        );
+        assert_eq!(
+            Kennitala::validate_prefix("8"),
+            Err(KennitalaError::InvalidDay)
+        );
+    }
+
+    #[test]
+    fn month() {
+        assert_eq!(next_digits("31"), [0, 1]);
+        assert_eq!(next_digits("310"), [1, 3, 5, 7, 8]);
+        assert_eq!(next_digits("311"), [0, 2]);
+        assert_eq!(next_digits("300"), [1, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(
+            Kennitala::validate_prefix("3113"),
+            Err(KennitalaError::InvalidMonth)
+        );
+        assert_eq!(
+            Kennitala::validate_prefix("012"),
+            Err(KennitalaError::InvalidMonth)
+        );
+        assert_eq!(
+            Kennitala::validate_prefix("3102"),
+            Err(KennitalaError::InvalidDay)
+        );
+        assert_eq!(
+            Kennitala::validate_prefix("3104"),
+            Err(KennitalaError::InvalidDay)
+        );
+        assert_eq!(
+            Kennitala::validate_prefix("7104"),
+            Err(KennitalaError::InvalidDay)
+        );
+    }
+
+    #[test]
+    fn leap_years() {
+        assert_eq!(next_digits("2902"), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(next_digits("29020"), [0, 4, 8]);
+        assert_eq!(next_digits("29021"), [2, 6]);
+        assert_eq!(
+            Kennitala::validate_prefix("290201"),
+            Err(KennitalaError::InvalidDay)
+        );
+        // Only 2000 was a leap year, not 1800 or 1900.
+        assert_eq!(next_digits("290200"), [2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(next_digits("29020022"), [9]);
+        assert_eq!(next_digits("290200229"), [0]);
+    }
+
+    #[test]
+    fn random_digits() {
+        assert_eq!(next_digits("311000"), [2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(
+            Kennitala::validate_prefix("3110001"),
+            Err(KennitalaError::InvalidRandomDigits)
+        );
+    }
+
+    #[test]
+    fn checksum_and_century() {
+        assert_eq!(next_digits("31100029"), [2]);
+        assert_eq!(next_digits("311000292"), [0, 8, 9]);
+        // These random digits result in a checksum of 10.
+        assert_eq!(
+            Kennitala::validate_prefix("31100025"),
+            Err(KennitalaError::InvalidChecksum)
+        );
+        assert!(!next_digits("3110002").contains(&5));
+        assert_eq!(
+            Kennitala::validate_prefix("311000293"),
+            Err(KennitalaError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn complete() {
+        let kt = Kennitala::validate_prefix("3110002920");
+        assert_eq!(
+            kt,
+            Ok(PrefixStatus::Complete(
+                Kennitala::new("3110002920").unwrap()
+            ))
+        );
+        let kt = Kennitala::validate_prefix("3110002921");
+        assert_eq!(kt, Err(KennitalaError::InvalidCentury));
+        let kt = Kennitala::validate_prefix("31100029200");
+        assert_eq!(kt, Err(KennitalaError::InvalidLength(11)));
+        let kt = Kennitala::validate_prefix("311-");
+        assert_eq!(
+            kt,
+            Err(KennitalaError::InvalidNumber {
+                index: 3,
+                found: '-'
+            })
+        );
+    }
+
+    #[test]
+    fn every_prefix_of_valid_kennitala() {
+        for kt in &[
+            "3110002920",
+            "0101502998",
+            "2902002290",
+            "7112993009",
+            "4301993079",
+        ] {
+            for length in 0..10 {
+                let (prefix, rest) = kt.split_at(length);
+                let next = rest.as_bytes()[0] - b'0';
+                assert!(next_digits(prefix).contains(&next), "{:?}", prefix);
+            }
+        }
+    }
+
+    #[test]
+    fn digit_set() {
+        let mut set = DigitSet::default();
+        assert!(set.is_empty());
+        set.insert(3);
+        set.insert(7);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert!(!set.contains(13));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3, 7]);
+    }
+}
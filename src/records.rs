@@ -0,0 +1,293 @@
+//! Parsing of kennitölur in fixed-width records, such as those in exports from
+//! the national registry, where each record has the kennitala at a known byte
+//! offset with no separators.
+//!
+//! ```
+//! use kennitolur::records::RecordReader;
+//!
+//! let dump = b"0001 Jon Jonsson       3110002920 Reykjavik\r\n\
+//!              0002 Gudrun Jonsdottir 1703715939 Akureyri\r\n";
+//! for record in RecordReader::new(&dump[..], 23) {
+//!     let (line_number, kennitala) = record.unwrap();
+//!     println!("{}: {}", line_number, kennitala.unwrap());
+//! }
+//! ```
+use crate::{Kennitala, KennitalaError};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Read};
+
+/// Errors which can come up when parsing a kennitala in a fixed-width record.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordError {
+    /// The record ends before the end of the kennitala field, after the given
+    /// number of bytes of the field.
+    TooShort(usize),
+    /// The kennitala field does not contain a valid kennitala. Positions in
+    /// `KennitalaError::InvalidNumber` errors are byte offsets into the
+    /// record.
+    Kennitala(KennitalaError),
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::TooShort(n) => {
+                write!(f, "Record ends after {} bytes of the kennitala", n)
+            }
+            RecordError::Kennitala(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for RecordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RecordError::TooShort(_) => None,
+            RecordError::Kennitala(e) => Some(e),
+        }
+    }
+}
+
+impl From<KennitalaError> for RecordError {
+    fn from(e: KennitalaError) -> Self {
+        RecordError::Kennitala(e)
+    }
+}
+
+impl From<RecordError> for KennitalaError {
+    /// A record which is too short is reported as a kennitala of the wrong
+    /// length.
+    fn from(e: RecordError) -> Self {
+        match e {
+            RecordError::TooShort(n) => KennitalaError::InvalidLength(n),
+            RecordError::Kennitala(e) => e,
+        }
+    }
+}
+
+/// Parse the kennitala starting at the given byte offset in the given record.
+/// A trailing line terminator, either `\n` or `\r\n`, is ignored. The rest of
+/// the record is not looked at, and need not be valid UTF-8.
+pub fn parse_fixed_width(line: &[u8], offset: usize) -> Result<Kennitala, RecordError> {
+    let line = strip_line_terminator(line);
+    let field = line.get(offset..).unwrap_or_default();
+    if field.len() < 10 {
+        return Err(RecordError::TooShort(field.len()));
+    }
+    Kennitala::from_bytes(&field[..10]).map_err(|e| match e {
+        KennitalaError::InvalidNumber { index, found } => {
+            RecordError::Kennitala(KennitalaError::InvalidNumber {
+                index: offset + index,
+                found,
+            })
+        }
+        e => RecordError::Kennitala(e),
+    })
+}
+
+/// Remove a trailing `\n` or `\r\n` from the given line.
+fn strip_line_terminator(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+/// An iterator over the kennitölur in a reader of fixed-width records, along
+/// with their 1-based line numbers.
+///
+/// By default, each line is a record, and lines may end with either `\n` or
+/// `\r\n`. Records which end before the end of the kennitala field, such as
+/// a truncated final line, are reported as `KennitalaError::InvalidLength`.
+#[derive(Debug)]
+pub struct RecordReader<R> {
+    reader: R,
+    offset: usize,
+    record_length: Option<usize>,
+    line_number: usize,
+    record: Vec<u8>,
+}
+
+impl<R: BufRead> RecordReader<R> {
+    /// Read records from the given reader, with the kennitala at the given
+    /// byte offset in each record.
+    pub fn new(reader: R, offset: usize) -> Self {
+        RecordReader {
+            reader,
+            offset,
+            record_length: None,
+            line_number: 0,
+            record: Vec::new(),
+        }
+    }
+
+    /// Split the records every `record_length` bytes rather than at line
+    /// terminators, for dumps which do not separate their records. A line
+    /// terminator directly after a record is skipped, so this also works for
+    /// dumps which do.
+    pub fn record_length(mut self, record_length: usize) -> Self {
+        self.record_length = Some(record_length);
+        self
+    }
+
+    /// Read the next record into `self.record`. Returns `false` at the end of
+    /// the reader.
+    fn read_record(&mut self) -> io::Result<bool> {
+        self.record.clear();
+        match self.record_length {
+            None => {
+                self.reader.read_until(b'\n', &mut self.record)?;
+            }
+            Some(record_length) => {
+                (&mut self.reader)
+                    .take(record_length as u64)
+                    .read_to_end(&mut self.record)?;
+                if self.reader.fill_buf()?.first() == Some(&b'\r') {
+                    self.reader.consume(1);
+                }
+                if self.reader.fill_buf()?.first() == Some(&b'\n') {
+                    self.reader.consume(1);
+                }
+            }
+        }
+        Ok(!self.record.is_empty())
+    }
+}
+
+impl<R: BufRead> Iterator for RecordReader<R> {
+    type Item = io::Result<(usize, Result<Kennitala, KennitalaError>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_record() {
+            Ok(true) => {
+                self.line_number += 1;
+                let kennitala =
+                    parse_fixed_width(&self.record, self.offset).map_err(KennitalaError::from);
+                Some(Ok((self.line_number, kennitala)))
+            }
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Registry-like records, with the name in Latin-1 padded to 20 bytes, and
+    // the kennitala at byte offset 26.
+    const LF_DUMP: &[u8] = b"00001 J\xf3n J\xf3nsson         3110002920 Reykjav\xedk\n\
+00002 Gu\xf0r\xfan J\xf3nsd\xf3ttir   1703715939 Akureyri\n\
+00003 \xc6gir hf.            7112993009 Hafnarfj\xf6r\xf0ur\n";
+
+    fn collect<R: BufRead>(reader: RecordReader<R>) -> Vec<(usize, String)> {
+        reader
+            .map(|record| {
+                let (line_number, kennitala) = record.unwrap();
+                let kennitala = match kennitala {
+                    Ok(kt) => kt.to_string(),
+                    Err(e) => format!("{:?}", e),
+                };
+                (line_number, kennitala)
+            })
+            .collect()
+    }
+
+    fn expected() -> Vec<(usize, String)> {
+        vec![
+            (1, "3110002920".to_string()),
+            (2, "1703715939".to_string()),
+            (3, "7112993009".to_string()),
+        ]
+    }
+
+    #[test]
+    fn parse_fixed_width_valid() {
+        let kt = parse_fixed_width(
+            b"00001 J\xf3n J\xf3nsson         3110002920 Reykjav\xedk",
+            26,
+        );
+        assert_eq!(kt.unwrap().to_string(), "3110002920");
+        let kt = parse_fixed_width(b"3110002920\r\n", 0);
+        assert_eq!(kt.unwrap().to_string(), "3110002920");
+    }
+
+    #[test]
+    fn parse_fixed_width_invalid() {
+        let kt = parse_fixed_width(b"xx3110002930xx", 2);
+        assert_eq!(
+            kt,
+            Err(RecordError::Kennitala(KennitalaError::InvalidChecksum))
+        );
+        let kt = parse_fixed_width(b"xx31100O2920xx", 2);
+        assert_eq!(
+            kt,
+            Err(RecordError::Kennitala(KennitalaError::InvalidNumber {
+                index: 7,
+                found: 'O'
+            }))
+        );
+        let kt = parse_fixed_width(b"xx3110002\r\n", 2);
+        assert_eq!(kt, Err(RecordError::TooShort(7)));
+        let kt = parse_fixed_width(b"xx", 5);
+        assert_eq!(kt, Err(RecordError::TooShort(0)));
+    }
+
+    #[test]
+    fn line_terminators() {
+        assert_eq!(collect(RecordReader::new(LF_DUMP, 26)), expected());
+        let crlf_dump: Vec<u8> = LF_DUMP
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .flat_map(|line| line.iter().chain(b"\r\n").copied())
+            .collect();
+        assert_eq!(collect(RecordReader::new(&crlf_dump[..], 26)), expected());
+        let unterminated = &LF_DUMP[..LF_DUMP.len() - 1];
+        assert_eq!(collect(RecordReader::new(unterminated, 26)), expected());
+    }
+
+    #[test]
+    fn short_final_line() {
+        let dump = b"00001 Jon Jonsson          3110002920 Reykjavik\n00002 Gudrun Jonsd";
+        let records = collect(RecordReader::new(&dump[..], 27));
+        assert_eq!(
+            records,
+            vec![
+                (1, "3110002920".to_string()),
+                (2, "InvalidLength(0)".to_string()),
+            ]
+        );
+        let dump =
+            b"00001 Jon Jonsson          3110002920 Reykjavik\n00002 Gudrun Jonsdottir    170371\n";
+        let records = collect(RecordReader::new(&dump[..], 27));
+        assert_eq!(records[1], (2, "InvalidLength(6)".to_string()));
+    }
+
+    #[test]
+    fn invalid_records_do_not_stop_reading() {
+        let dump = b"a 3110002930\nb 31100O2920\n\nd 1703715939\n";
+        let records = collect(RecordReader::new(&dump[..], 2));
+        assert_eq!(
+            records,
+            vec![
+                (1, "InvalidChecksum".to_string()),
+                (2, "InvalidNumber { index: 7, found: 'O' }".to_string()),
+                (3, "InvalidLength(0)".to_string()),
+                (4, "1703715939".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn fixed_record_length() {
+        let dump = b"a 3110002920 xb 1703715939 \xffc 7112993009 x";
+        let records = collect(RecordReader::new(&dump[..], 2).record_length(14));
+        assert_eq!(records, expected());
+        let dump = b"a 3110002920 x\r\nb 1703715939 \xff\r\nc 7112993009 x\r\n";
+        let records = collect(RecordReader::new(&dump[..], 2).record_length(14));
+        assert_eq!(records, expected());
+        let dump = b"a 3110002920 xb 170371";
+        let records = collect(RecordReader::new(&dump[..], 2).record_length(14));
+        assert_eq!(records[1], (2, "InvalidLength(6)".to_string()));
+    }
+}
@@ -0,0 +1,146 @@
+//! Use of kennitölur as Redis keys and values through `redis`. A kennitala is
+//! written as its canonical 10 digit string, and can be read from a string or
+//! an integer reply. Reading validates the kennitala, and an invalid one
+//! results in a `TypeError` whose detail is the `KennitalaError` message. A nil
+//! reply is an error as well; read an `Option<Kennitala>` when the key may be
+//! missing.
+use core::convert::TryFrom;
+
+use redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+use crate::error::NegativeKennitala;
+use crate::{Kennitala, KennitalaError};
+
+impl ToRedisArgs for Kennitala {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.to_fixed().as_ref());
+    }
+}
+
+impl FromRedisValue for Kennitala {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let kennitala = match v {
+            Value::BulkString(bytes) => Kennitala::from_bytes(bytes),
+            Value::SimpleString(s) => Kennitala::new(s),
+            Value::Int(n) => {
+                let n = u64::try_from(*n).map_err(|_| {
+                    RedisError::from((
+                        ErrorKind::TypeError,
+                        "Invalid kennitala",
+                        NegativeKennitala(*n).to_string(),
+                    ))
+                })?;
+                Kennitala::from_u64(n)
+            }
+            _ => {
+                return Err(RedisError::from((
+                    ErrorKind::TypeError,
+                    "Response was of incompatible type",
+                    format!("Expected a kennitala, found {:?}", v),
+                )))
+            }
+        };
+        kennitala.map_err(invalid_kennitala)
+    }
+}
+
+fn invalid_kennitala(e: KennitalaError) -> RedisError {
+    RedisError::from((ErrorKind::TypeError, "Invalid kennitala", e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::{cmd, from_redis_value};
+
+    #[test]
+    fn arguments() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        assert_eq!(kt.to_redis_args(), vec![b"0311203149".to_vec()]);
+        let packed = cmd("SET").arg(kt).arg("Jón").get_packed_command();
+        let expected = "*3\r\n$3\r\nSET\r\n$10\r\n0311203149\r\n$4\r\nJón\r\n";
+        assert_eq!(packed, expected.as_bytes());
+    }
+
+    #[test]
+    fn replies() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        let replies = [
+            Value::BulkString(b"0311203149".to_vec()),
+            Value::SimpleString("0311203149".to_string()),
+            Value::Int(311203149),
+        ];
+        for reply in &replies {
+            assert_eq!(from_redis_value::<Kennitala>(reply).unwrap(), kt);
+        }
+        assert_eq!(
+            from_redis_value::<Option<Kennitala>>(&Value::Nil).unwrap(),
+            None
+        );
+        assert_eq!(
+            from_redis_value::<Option<Kennitala>>(&replies[0]).unwrap(),
+            Some(kt)
+        );
+    }
+
+    #[test]
+    fn nil_reply() {
+        let error = from_redis_value::<Kennitala>(&Value::Nil).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::TypeError);
+        assert_eq!(error.detail(), Some("Expected a kennitala, found nil"));
+    }
+
+    #[test]
+    fn wrong_type_reply() {
+        let replies = [
+            Value::Okay,
+            Value::Double(311203149.0),
+            Value::Array(vec![Value::BulkString(b"0311203149".to_vec())]),
+        ];
+        for reply in &replies {
+            let error = from_redis_value::<Kennitala>(reply).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::TypeError, "{:?}", reply);
+            assert_eq!(
+                error.detail(),
+                Some(&*format!("Expected a kennitala, found {:?}", reply))
+            );
+        }
+    }
+
+    #[test]
+    fn corrupt_reply() {
+        let cases = [
+            (
+                Value::BulkString(b"0311203159".to_vec()),
+                KennitalaError::InvalidChecksum.to_string(),
+            ),
+            (
+                Value::BulkString(b"031120314".to_vec()),
+                KennitalaError::InvalidLength(9).to_string(),
+            ),
+            (
+                Value::BulkString(b"0311\xff03149".to_vec()),
+                Kennitala::from_bytes(b"0311\xff03149")
+                    .unwrap_err()
+                    .to_string(),
+            ),
+            (
+                Value::Int(311203159),
+                KennitalaError::InvalidChecksum.to_string(),
+            ),
+            (
+                Value::Int(-311203149),
+                "Kennitala can not be negative, found -311203149".to_string(),
+            ),
+        ];
+        for (reply, detail) in &cases {
+            let error = from_redis_value::<Kennitala>(reply).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::TypeError, "{:?}", reply);
+            assert_eq!(error.detail(), Some(detail.as_str()), "{:?}", reply);
+            assert!(error.to_string().contains(detail.as_str()), "{}", error);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Storage of kennitölur in SQLite through `rusqlite`. A kennitala is stored
+//! as its canonical 10 digit string, and can be read from either such a string
+//! or an integer. Reading validates the kennitala, so an invalid value results
+//! in `FromSqlError::Other` holding the `KennitalaError`.
+use core::convert::TryFrom;
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::Kennitala;
+
+impl ToSql for Kennitala {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl FromSql for Kennitala {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let kennitala = match value {
+            ValueRef::Text(_) => Kennitala::new(value.as_str()?),
+            ValueRef::Integer(integer) => {
+                let integer =
+                    u64::try_from(integer).map_err(|_| FromSqlError::OutOfRange(integer))?;
+                Kennitala::from_u64(integer)
+            }
+            _ => return Err(FromSqlError::InvalidType),
+        };
+        kennitala.map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KennitalaError;
+    use rusqlite::{params, Connection};
+
+    fn database() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE people (id INTEGER PRIMARY KEY, kennitala)")
+            .unwrap();
+        conn
+    }
+
+    fn read(conn: &Connection, id: i64) -> rusqlite::Result<Kennitala> {
+        conn.query_row(
+            "SELECT kennitala FROM people WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let conn = database();
+        let kt = Kennitala::new("0311203149").unwrap();
+        conn.execute("INSERT INTO people VALUES (1, ?1)", params![kt])
+            .unwrap();
+        let stored: String = conn
+            .query_row("SELECT kennitala FROM people", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, "0311203149");
+        assert_eq!(read(&conn, 1).unwrap(), kt);
+    }
+
+    #[test]
+    fn integer_column() {
+        let conn = database();
+        conn.execute_batch(
+            "INSERT INTO people VALUES (1, 311203149);
+             INSERT INTO people VALUES (2, 7112993009);",
+        )
+        .unwrap();
+        assert_eq!(
+            read(&conn, 1).unwrap(),
+            Kennitala::new("0311203149").unwrap()
+        );
+        assert_eq!(
+            read(&conn, 2).unwrap(),
+            Kennitala::new("7112993009").unwrap()
+        );
+    }
+
+    #[test]
+    fn null() {
+        let conn = database();
+        conn.execute_batch("INSERT INTO people VALUES (1, NULL)")
+            .unwrap();
+        let kt: Option<Kennitala> = conn
+            .query_row("SELECT kennitala FROM people", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(kt, None);
+    }
+
+    #[test]
+    fn invalid_values() {
+        let conn = database();
+        conn.execute_batch(
+            "INSERT INTO people VALUES (1, '3110002921');
+             INSERT INTO people VALUES (2, 3110002921);
+             INSERT INTO people VALUES (3, -3110002920);
+             INSERT INTO people VALUES (4, 3110002920.0);",
+        )
+        .unwrap();
+        for id in 1..=2 {
+            match read(&conn, id) {
+                Err(rusqlite::Error::FromSqlConversionFailure(_, _, e)) => {
+                    let e = e.downcast_ref::<KennitalaError>().unwrap();
+                    assert_eq!(*e, KennitalaError::InvalidCentury);
+                }
+                result => panic!("expected a conversion failure, got {:?}", result),
+            }
+        }
+        assert!(matches!(
+            read(&conn, 3),
+            Err(rusqlite::Error::IntegralValueOutOfRange(_, -3110002920))
+        ));
+        assert!(matches!(
+            read(&conn, 4),
+            Err(rusqlite::Error::InvalidColumnType(..))
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+use core::ops::Range;
+
+use crate::Kennitala;
+
+/// Find all valid kennitölur in the given text, such as a log line or an
+/// email. Both the `3110002920` and `311000-2920` forms are recognized. The
+/// returned iterator yields the byte range of each kennitala within the text,
+/// so that callers can highlight or redact it, along with the kennitala itself.
+///
+/// Digits which are part of a longer run of digits, such as a phone number or
+/// an account number, are never matched.
+///
+/// ```
+/// let text = "Kennitala 311000-2920, sími 5551234.";
+/// let found: Vec<_> = kennitolur::find_all(text).collect();
+/// assert_eq!(found.len(), 1);
+/// assert_eq!(&text[found[0].0.clone()], "311000-2920");
+/// ```
+pub fn find_all(text: &str) -> FindAll<'_> {
+    FindAll { text, position: 0 }
+}
+
+/// Iterator over the kennitölur in a text. See `find_all` for details.
+#[derive(Debug, Clone)]
+pub struct FindAll<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Iterator for FindAll<'a> {
+    type Item = (Range<usize>, Kennitala);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        while self.position < bytes.len() {
+            let start = self.position;
+            if !bytes[start].is_ascii_digit() {
+                self.position += 1;
+                continue;
+            }
+
+            // Only whole runs of digits are considered, so that kennitölur
+            // are never found within longer numbers.
+            let end = digit_run_end(bytes, start);
+            self.position = end;
+            let range = match end - start {
+                10 => start..end,
+                6 if bytes.get(end) == Some(&b'-') => {
+                    let tail_end = digit_run_end(bytes, end + 1);
+                    if tail_end - (end + 1) != 4 {
+                        continue;
+                    }
+                    start..tail_end
+                }
+                _ => continue,
+            };
+
+            if let Ok(kt) = Kennitala::new_lenient(&self.text[range.clone()]) {
+                self.position = range.end;
+                return Some((range, kt));
+            }
+        }
+        None
+    }
+}
+
+/// Find the end of the run of ASCII digits which starts at the given position.
+fn digit_run_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|b| !b.is_ascii_digit())
+        .map_or(bytes.len(), |length| start + length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<(Range<usize>, String)> {
+        find_all(text)
+            .map(|(range, kt)| (range, kt.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn both_forms() {
+        let text = "3110002920 og 170371-5939";
+        assert_eq!(
+            found(text),
+            [
+                (0..10, "3110002920".to_string()),
+                (14..25, "1703715939".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn surrounded_by_text() {
+        let text = "kt:3110002920, (7112993009). Ýmislegt: 170371-5939!";
+        let ranges: Vec<_> = found(text).into_iter().map(|(r, _)| r).collect();
+        assert_eq!(ranges.len(), 3);
+        assert_eq!(&text[ranges[0].clone()], "3110002920");
+        assert_eq!(&text[ranges[1].clone()], "7112993009");
+        assert_eq!(&text[ranges[2].clone()], "170371-5939");
+    }
+
+    #[test]
+    fn longer_digit_runs() {
+        assert!(found("+354 3110002920123").is_empty());
+        assert!(found("0013110002920").is_empty());
+        assert!(found("31100029200").is_empty());
+        assert!(found("12311000-2920").is_empty());
+        assert!(found("311000-29201").is_empty());
+        assert!(found("311000-292").is_empty());
+    }
+
+    #[test]
+    fn invalid_candidates() {
+        assert!(found("3110002921 311000-2921").is_empty());
+        assert!(found("").is_empty());
+        assert!(found("-------").is_empty());
+        assert_eq!(found("3110002921 3110002920").len(), 1);
+    }
+
+    #[test]
+    fn adjacent_separators() {
+        let text = "3110002920-1703715939";
+        assert_eq!(found(text).len(), 2);
+        let text = "123456-3110002920";
+        assert_eq!(found(text), [(7..17, "3110002920".to_string())]);
+    }
+}
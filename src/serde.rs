@@ -0,0 +1,682 @@
+//! Serialization of kennitölur through `serde`. In human-readable formats, such
+//! as JSON, a kennitala is serialized as its canonical 10 digit string, and can
+//! be deserialized either from such a string or from its integer form, such as
+//! the JSON number `3110002920`. Numbers lose the leading zero of days of birth
+//! below 10, so both 9 and 10 digits are accepted, but fewer are an error. Other
+//! formats, such as bincode, hold the packed `u32` documented in
+//! `Kennitala::as_u32`, which is validated as with `Kennitala::new` when
+//! deserialized. In particular, values with the invalid checksum flag set are
+//! rejected, although `Kennitala::from_packed` accepts them.
+//!
+//! The submodules can be used with `#[serde(with = "...")]` on `Kennitala`
+//! fields to choose a different representation.
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::error::NegativeKennitala;
+use crate::Kennitala;
+
+impl Serialize for Kennitala {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u32(self.as_u32())
+        }
+    }
+}
+
+struct KennitalaVisitor;
+
+impl<'de> Visitor<'de> for KennitalaVisitor {
+    type Value = Kennitala;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a kennitala as a string or an integer")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Kennitala::new(value).map_err(E::custom)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // A JSON number drops the leading zero of a day of birth below 10, so
+        // 9 digits are fine, but no kennitala has fewer.
+        if value < 100_000_000 {
+            return Err(E::invalid_value(
+                de::Unexpected::Unsigned(value),
+                &"a kennitala with 9 or 10 digits",
+            ));
+        }
+        Kennitala::from_decimal_u64(value).map_err(E::custom)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if value < 0 {
+            return Err(E::custom(NegativeKennitala(value)));
+        }
+        self.visit_u64(value as u64)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Err(E::custom(format_args!(
+            "a kennitala must be an integer, found {}",
+            value
+        )))
+    }
+}
+
+impl<'de> Deserialize<'de> for Kennitala {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(KennitalaVisitor)
+        } else {
+            let packed = u32::deserialize(deserializer)?;
+            // Only what `Kennitala::new` could have produced is accepted from
+            // the wire, not kennitölur with an invalid checksum.
+            Kennitala::from_packed(packed)
+                .filter(Kennitala::has_valid_checksum)
+                .ok_or_else(|| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Unsigned(u64::from(packed)),
+                        &"a packed kennitala",
+                    )
+                })
+        }
+    }
+}
+
+/// Serialize a kennitala as an integer, such as `3110002920`, and deserialize
+/// it from one. Kennitölur whose day starts with a zero are 9 digit integers.
+///
+/// ```
+/// # use kennitolur::Kennitala;
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct Person {
+///     #[serde(with = "kennitolur::serde::as_u64")]
+///     kennitala: Kennitala,
+/// }
+/// ```
+pub mod as_u64 {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::Kennitala;
+
+    /// Serialize the kennitala with `Kennitala::to_decimal_u64`.
+    pub fn serialize<S>(kennitala: &Kennitala, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(kennitala.to_decimal_u64())
+    }
+
+    /// Deserialize the kennitala with `Kennitala::from_u64`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Kennitala, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = u64::deserialize(deserializer)?;
+        Kennitala::from_u64(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Like `as_u64`, but with a `u32`. Company kennitölur can exceed `u32::MAX`,
+/// and serializing those fails.
+pub mod as_u32 {
+    use core::convert::TryFrom;
+
+    use serde::{ser, Deserialize, Deserializer, Serializer};
+
+    use crate::Kennitala;
+
+    /// Serialize the kennitala as a `u32`, failing if it does not fit.
+    pub fn serialize<S>(kennitala: &Kennitala, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = u32::try_from(kennitala.to_decimal_u64())
+            .map_err(|_| ser::Error::custom("kennitala does not fit in a u32"))?;
+        serializer.serialize_u32(value)
+    }
+
+    /// Deserialize the kennitala with `Kennitala::from_u32`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Kennitala, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = u32::deserialize(deserializer)?;
+        Kennitala::from_u32(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serialize a kennitala with a hyphen, such as `"311000-2920"`, and
+/// deserialize it from a string with or without the hyphen, as accepted by
+/// `Kennitala::new_lenient`.
+pub mod hyphenated {
+    use std::fmt;
+
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::Serializer;
+
+    use crate::Kennitala;
+
+    struct HyphenatedVisitor;
+
+    impl<'de> Visitor<'de> for HyphenatedVisitor {
+        type Value = Kennitala;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a kennitala as a string, optionally with a hyphen")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Kennitala::new_lenient(value).map_err(E::custom)
+        }
+    }
+
+    /// Serialize the kennitala with `Kennitala::to_string_pretty`.
+    pub fn serialize<S>(kennitala: &Kennitala, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&kennitala.to_string_pretty())
+    }
+
+    /// Deserialize the kennitala with `Kennitala::new_lenient`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Kennitala, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(HyphenatedVisitor)
+    }
+}
+
+/// Deserialize a kennitala from either a string or an integer, whichever the
+/// input holds, and serialize it as a string, in every format. The
+/// implementations of `Kennitala` only do this in human-readable formats, and
+/// use the packed `u32` in others. This keeps the string form in
+/// self-describing binary formats, such as MessagePack or BSON written with
+/// `bson::to_vec`, but can not be used with formats such as bincode, which
+/// need to know the type being deserialized.
+pub mod flexible {
+    use serde::{Deserializer, Serializer};
+
+    use super::KennitalaVisitor;
+    use crate::Kennitala;
+
+    /// Serialize the kennitala as its canonical string, in every format.
+    pub fn serialize<S>(kennitala: &Kennitala, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(kennitala)
+    }
+
+    /// Deserialize the kennitala from a string or an integer.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Kennitala, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(KennitalaVisitor)
+    }
+}
+
+/// Deserialize an `Option<Kennitala>` where an empty or whitespace-only
+/// string, as well as `null`, means `None`. Other strings and integers are
+/// validated as usual. `None` is serialized as `null`, or can be left out
+/// entirely with `#[serde(skip_serializing_if = "Option::is_none")]`. Add
+/// `#[serde(default)]` to also accept a missing field.
+///
+/// ```
+/// # use kennitolur::Kennitala;
+/// #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+/// struct Form {
+///     #[serde(default, with = "kennitolur::serde::option_empty_as_none")]
+///     kennitala: Option<Kennitala>,
+/// }
+/// ```
+pub mod option_empty_as_none {
+    use std::fmt;
+
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::Serializer;
+
+    use super::KennitalaVisitor;
+    use crate::Kennitala;
+
+    struct OptionVisitor;
+
+    impl<'de> Visitor<'de> for OptionVisitor {
+        type Value = Option<Kennitala>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a kennitala, an empty string or null")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if value.trim().is_empty() {
+                Ok(None)
+            } else {
+                KennitalaVisitor.visit_str(value).map(Some)
+            }
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            KennitalaVisitor.visit_u64(value).map(Some)
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            KennitalaVisitor.visit_i64(value).map(Some)
+        }
+    }
+
+    /// Serialize the kennitala as its canonical string, in every format, or
+    /// `None` as `null`.
+    pub fn serialize<S>(kennitala: &Option<Kennitala>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match kennitala {
+            Some(kennitala) => serializer.serialize_some(&kennitala.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize the kennitala, treating empty strings and `null` as `None`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Kennitala>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let kt = Kennitala::new("3110002920").unwrap();
+        let json = serde_json::to_string(&kt).unwrap();
+        assert_eq!(json, "\"3110002920\"");
+        let deserialized: Kennitala = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, kt);
+    }
+
+    #[test]
+    fn json_from_integer() {
+        let kt: Kennitala = serde_json::from_str("1703715939").unwrap();
+        assert_eq!(kt, Kennitala::new("1703715939").unwrap());
+    }
+
+    #[test]
+    fn json_string_and_number() {
+        #[derive(Debug, serde_derive::Deserialize)]
+        struct Person {
+            kennitala: Kennitala,
+        }
+
+        for (string, number) in &[
+            (
+                r#"{"kennitala": "3110002920"}"#,
+                r#"{"kennitala": 3110002920}"#,
+            ),
+            (
+                r#"{"kennitala": "0311203149"}"#,
+                r#"{"kennitala": 311203149}"#,
+            ),
+            (
+                r#"{"kennitala": "7112993009"}"#,
+                r#"{"kennitala": 7112993009}"#,
+            ),
+        ] {
+            let from_string: Person = serde_json::from_str(string).unwrap();
+            let from_number: Person = serde_json::from_str(number).unwrap();
+            assert_eq!(from_string.kennitala, from_number.kennitala);
+            let value: serde_json::Value = serde_json::from_str(number).unwrap();
+            let from_value: Person = serde_json::from_value(value).unwrap();
+            assert_eq!(from_value.kennitala, from_number.kennitala);
+        }
+    }
+
+    #[test]
+    fn json_number_too_short() {
+        let err = serde_json::from_str::<Kennitala>("31100292").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value: integer `31100292`, expected a kennitala with 9 or 10 digits \
+             at line 1 column 8"
+        );
+        let err = serde_json::from_value::<Kennitala>(serde_json::json!(0)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value: integer `0`, expected a kennitala with 9 or 10 digits"
+        );
+        let err = serde_json::from_str::<Kennitala>("311203159").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The kennitala's checksum is invalid at line 1 column 9"
+        );
+    }
+
+    #[test]
+    fn json_invalid() {
+        let err = serde_json::from_str::<Kennitala>("\"3110002921\"").unwrap_err();
+        assert!(err.to_string().contains("Century of birth is invalid"));
+        assert!(serde_json::from_str::<Kennitala>("31100029200").is_err());
+        assert!(serde_json::from_str::<Kennitala>("true").is_err());
+    }
+
+    #[test]
+    fn json_borrowed() {
+        #[derive(serde_derive::Deserialize)]
+        struct Person<'a> {
+            name: &'a str,
+            kennitala: Kennitala,
+        }
+
+        let json = r#"{"name": "Jón", "kennitala": "3110002920"}"#;
+        let person: Person<'_> = serde_json::from_str(json).unwrap();
+        assert_eq!(person.name, "Jón");
+        assert_eq!(person.kennitala, Kennitala::new("3110002920").unwrap());
+    }
+
+    #[test]
+    fn yaml_round_trip() {
+        let kt = Kennitala::new("7112993009").unwrap();
+        let yaml = serde_yaml::to_string(&kt).unwrap();
+        assert_eq!(yaml.trim(), "'7112993009'");
+        assert_eq!(serde_yaml::from_str::<Kennitala>(&yaml).unwrap(), kt);
+        assert_eq!(serde_yaml::from_str::<Kennitala>("7112993009").unwrap(), kt);
+        let err = serde_yaml::from_str::<Kennitala>("'7112993019'").unwrap_err();
+        assert!(err.to_string().contains("checksum is invalid"));
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        for kt in &["0311203149", "3110002920", "7112993009"] {
+            let kt = Kennitala::new(kt).unwrap();
+            let bytes = bincode::serialize(&kt).unwrap();
+            assert_eq!(bytes.len(), 4);
+            assert_eq!(bincode::deserialize::<Kennitala>(&bytes).unwrap(), kt);
+        }
+        let bytes = bincode::serialize(&u32::MAX).unwrap();
+        let err = bincode::deserialize::<Kennitala>(&bytes).unwrap_err();
+        assert!(err.to_string().contains("a packed kennitala"), "{}", err);
+    }
+
+    #[test]
+    fn binary_invalid_checksum() {
+        let kt = Kennitala::new_unchecked_checksum("3110002930").unwrap();
+        assert_eq!(Kennitala::from_packed(kt.as_u32()), Some(kt));
+        let bytes = bincode::serialize(&kt).unwrap();
+        assert_eq!(bytes, 0x2525_015fu32.to_le_bytes());
+        let err = bincode::deserialize::<Kennitala>(&bytes).unwrap_err();
+        assert!(err.to_string().contains("a packed kennitala"), "{}", err);
+        let bytes = postcard::to_allocvec(&kt).unwrap();
+        assert!(postcard::from_bytes::<Kennitala>(&bytes).is_err());
+    }
+
+    #[test]
+    fn postcard_round_trip() {
+        let kts = [
+            Kennitala::new("0311203149").unwrap(),
+            Kennitala::new("3110002920").unwrap(),
+            Kennitala::new("7112993009").unwrap(),
+        ];
+        let bytes = postcard::to_allocvec(&kts).unwrap();
+        assert!((4 * kts.len()..=5 * kts.len()).contains(&bytes.len()));
+        assert_eq!(postcard::from_bytes::<[Kennitala; 3]>(&bytes).unwrap(), kts);
+        let bytes = postcard::to_allocvec(&0u32).unwrap();
+        assert!(postcard::from_bytes::<Kennitala>(&bytes).is_err());
+    }
+
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct AsU64 {
+        #[serde(with = "as_u64")]
+        kennitala: Kennitala,
+    }
+
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct AsU32 {
+        #[serde(with = "as_u32")]
+        kennitala: Kennitala,
+    }
+
+    #[test]
+    fn as_u64_leading_zero() {
+        let value = AsU64 {
+            kennitala: Kennitala::new("0311203149").unwrap(),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"kennitala":311203149}"#);
+        assert_eq!(serde_json::from_str::<AsU64>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn as_u64_company() {
+        let value = AsU64 {
+            kennitala: Kennitala::new("7112993009").unwrap(),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"kennitala":7112993009}"#);
+        assert_eq!(serde_json::from_str::<AsU64>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn as_u64_rejects_strings_and_invalid() {
+        assert!(serde_json::from_str::<AsU64>(r#"{"kennitala":"3110002920"}"#).is_err());
+        let err = serde_json::from_str::<AsU64>(r#"{"kennitala":3110002921}"#).unwrap_err();
+        assert!(err.to_string().contains("Century"));
+    }
+
+    #[test]
+    fn as_u32_round_trip() {
+        let value = AsU32 {
+            kennitala: Kennitala::new("0311203149").unwrap(),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"kennitala":311203149}"#);
+        assert_eq!(serde_json::from_str::<AsU32>(&json).unwrap(), value);
+
+        let company = AsU32 {
+            kennitala: Kennitala::new("7112993009").unwrap(),
+        };
+        assert!(serde_json::to_string(&company).is_err());
+    }
+
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct Hyphenated {
+        #[serde(with = "hyphenated")]
+        kennitala: Kennitala,
+    }
+
+    #[test]
+    fn hyphenated_round_trip() {
+        let value = Hyphenated {
+            kennitala: Kennitala::new("3110002920").unwrap(),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"kennitala":"311000-2920"}"#);
+        assert_eq!(serde_json::from_str::<Hyphenated>(&json).unwrap(), value);
+        let plain = r#"{"kennitala":"3110002920"}"#;
+        assert_eq!(serde_json::from_str::<Hyphenated>(plain).unwrap(), value);
+    }
+
+    #[test]
+    fn hyphenated_misplaced_hyphen() {
+        let json = r#"{"kennitala":"31100-02920"}"#;
+        let err = serde_json::from_str::<Hyphenated>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Invalid character '-' at position 5"),
+            "{}",
+            err
+        );
+    }
+
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct Flexible {
+        #[serde(with = "flexible")]
+        kennitala: Kennitala,
+    }
+
+    #[test]
+    fn flexible_json_value() {
+        let expected = Flexible {
+            kennitala: Kennitala::new("3110002920").unwrap(),
+        };
+        for value in &[
+            serde_json::json!({"kennitala": "3110002920"}),
+            serde_json::json!({"kennitala": 3110002920u64}),
+            serde_json::json!({"kennitala": 3110002920i64}),
+        ] {
+            let flexible: Flexible = serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(flexible, expected);
+        }
+
+        let value = serde_json::json!({"kennitala": -3110002920i64});
+        let err = serde_json::from_value::<Flexible>(value).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Kennitala can not be negative, found -3110002920"
+        );
+        let value = serde_json::json!({"kennitala": 3110002920.0});
+        let err = serde_json::from_value::<Flexible>(value).unwrap_err();
+        assert!(err.to_string().contains("must be an integer"), "{}", err);
+    }
+
+    #[test]
+    fn flexible_messagepack() {
+        #[derive(serde_derive::Serialize)]
+        struct Raw<T> {
+            kennitala: T,
+        }
+
+        let expected = Flexible {
+            kennitala: Kennitala::new("0311203149").unwrap(),
+        };
+        let bytes = rmp_serde::to_vec(&expected).unwrap();
+        assert_eq!(rmp_serde::from_slice::<Flexible>(&bytes).unwrap(), expected);
+        let bytes = rmp_serde::to_vec(&Raw {
+            kennitala: 311203149u32,
+        })
+        .unwrap();
+        assert_eq!(rmp_serde::from_slice::<Flexible>(&bytes).unwrap(), expected);
+        let bytes = rmp_serde::to_vec(&Raw { kennitala: -1i8 }).unwrap();
+        let err = rmp_serde::from_slice::<Flexible>(&bytes).unwrap_err();
+        assert_eq!(err.to_string(), "Kennitala can not be negative, found -1");
+    }
+
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct Form {
+        #[serde(default, with = "option_empty_as_none")]
+        kennitala: Option<Kennitala>,
+    }
+
+    #[test]
+    fn option_empty_as_none_json() {
+        let some = Form {
+            kennitala: Some(Kennitala::new("3110002920").unwrap()),
+        };
+        let none = Form { kennitala: None };
+        for (json, expected) in &[
+            (r#"{"kennitala":"3110002920"}"#, &some),
+            (r#"{"kennitala":3110002920}"#, &some),
+            (r#"{"kennitala":""}"#, &none),
+            (r#"{"kennitala":"  "}"#, &none),
+            (r#"{"kennitala":null}"#, &none),
+            (r#"{}"#, &none),
+        ] {
+            assert_eq!(serde_json::from_str::<Form>(json).unwrap(), **expected);
+        }
+        assert!(serde_json::from_str::<Form>(r#"{"kennitala":"3110002921"}"#).is_err());
+
+        assert_eq!(
+            serde_json::to_string(&some).unwrap(),
+            r#"{"kennitala":"3110002920"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&none).unwrap(),
+            r#"{"kennitala":null}"#
+        );
+    }
+
+    #[test]
+    fn option_empty_as_none_urlencoded() {
+        let some = Form {
+            kennitala: Some(Kennitala::new("3110002920").unwrap()),
+        };
+        let none = Form { kennitala: None };
+        assert_eq!(
+            serde_urlencoded::from_str::<Form>("kennitala=3110002920").unwrap(),
+            some
+        );
+        assert_eq!(
+            serde_urlencoded::from_str::<Form>("kennitala=").unwrap(),
+            none
+        );
+        assert_eq!(serde_urlencoded::from_str::<Form>("").unwrap(), none);
+        assert!(serde_urlencoded::from_str::<Form>("kennitala=311000-2920").is_err());
+
+        assert_eq!(
+            serde_urlencoded::to_string(&some).unwrap(),
+            "kennitala=3110002920"
+        );
+        assert_eq!(serde_urlencoded::to_string(&none).unwrap(), "");
+    }
+}
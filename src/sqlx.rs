@@ -0,0 +1,77 @@
+//! Storage of kennitölur in PostgreSQL through `sqlx`. A kennitala is encoded
+//! as its canonical 10 digit string, and can be decoded from `TEXT`,
+//! `VARCHAR` or `CHAR` columns. Decoding validates the kennitala, after
+//! removing the trailing spaces which a `CHAR` column wider than 10 characters
+//! pads it with, so a corrupt row results in a decode error.
+//!
+//! With the `query!` macros, the type of a column has to be overridden, as in
+//! `SELECT kennitala AS "kennitala: Kennitala" FROM people`.
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+use sqlx::{Decode, Encode, Type};
+
+use crate::Kennitala;
+
+impl Type<Postgres> for Kennitala {
+    fn type_info() -> PgTypeInfo {
+        <&str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <&str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for Kennitala {
+    fn array_type_info() -> PgTypeInfo {
+        <&str as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <&str as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for Kennitala {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <&str as Encode<'_, Postgres>>::encode(&self.to_fixed(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        10
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Kennitala {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let kennitala = <&str as Decode<'r, Postgres>>::decode(value)?;
+        Ok(Kennitala::new(kennitala.trim_end_matches(' '))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compatible_types() {
+        assert_eq!(
+            Kennitala::type_info(),
+            <String as Type<Postgres>>::type_info()
+        );
+        assert!(Kennitala::compatible(
+            &<String as Type<Postgres>>::type_info()
+        ));
+        assert!(!Kennitala::compatible(&<i64 as Type<Postgres>>::type_info()));
+    }
+
+    #[test]
+    fn encode() {
+        let kt = Kennitala::new("0311203149").unwrap();
+        let mut buf = PgArgumentBuffer::default();
+        let is_null = Encode::<'_, Postgres>::encode_by_ref(&kt, &mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        assert_eq!(&buf[..], b"0311203149");
+    }
+}
@@ -0,0 +1,90 @@
+//! OpenAPI schemas for kennitölur through `utoipa`. A kennitala is described
+//! as a string of 10 digits, matching its serialization through `serde` in
+//! human-readable formats.
+use std::borrow::Cow;
+
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaType, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::Kennitala;
+
+impl PartialSchema for Kennitala {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(SchemaType::Type(Type::String))
+            .description(Some("An Icelandic kennitala, as its 10 digits"))
+            .pattern(Some("^[0-9]{10}$"))
+            .min_length(Some(10))
+            .max_length(Some(10))
+            .examples(["3110002920"])
+            .into()
+    }
+}
+
+impl ToSchema for Kennitala {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Kennitala")
+    }
+}
+
+// The code generated by `utoipa` qualifies paths which are already in scope.
+#[allow(unused_qualifications)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+    use utoipa::{IntoParams, OpenApi};
+
+    #[allow(dead_code)]
+    #[derive(utoipa::ToSchema)]
+    struct Person {
+        name: String,
+        kennitala: Kennitala,
+    }
+
+    #[allow(dead_code)]
+    #[derive(utoipa::IntoParams)]
+    #[into_params(parameter_in = Query)]
+    struct Lookup {
+        kennitala: Kennitala,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Person)))]
+    struct ApiDoc;
+
+    #[test]
+    fn component_schema() {
+        let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let schemas = &doc["components"]["schemas"];
+        assert_eq!(
+            schemas["Kennitala"],
+            json!({
+                "type": "string",
+                "description": "An Icelandic kennitala, as its 10 digits",
+                "pattern": "^[0-9]{10}$",
+                "minLength": 10,
+                "maxLength": 10,
+                "examples": ["3110002920"],
+            })
+        );
+        assert_eq!(
+            schemas["Person"]["properties"]["kennitala"],
+            json!({"$ref": "#/components/schemas/Kennitala"})
+        );
+    }
+
+    #[test]
+    fn query_parameter() {
+        let params = serde_json::to_value(Lookup::into_params(|| None)).unwrap();
+        assert_eq!(params[0]["name"], "kennitala");
+        assert_eq!(params[0]["in"], "query");
+        assert_eq!(params[0]["required"], true);
+        assert_eq!(
+            params[0]["schema"],
+            json!({"$ref": "#/components/schemas/Kennitala"})
+        );
+    }
+}
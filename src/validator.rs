@@ -0,0 +1,112 @@
+//! Validation of kennitölur in structs deriving `validator::Validate`, so
+//! that they are reported along with every other field:
+//!
+//! ```
+//! use validator::Validate;
+//!
+//! #[derive(Validate)]
+//! struct NewPerson {
+//!     #[validate(custom(function = "kennitolur::validate_kennitala"))]
+//!     kennitala: String,
+//!     #[validate(custom(function = "kennitolur::validate_kennitala"))]
+//!     spouse: Option<String>,
+//! }
+//!
+//! let person = NewPerson {
+//!     kennitala: "3110002920".to_string(),
+//!     spouse: Some("3110002921".to_string()),
+//! };
+//! let errors = person.validate().unwrap_err();
+//! assert_eq!(errors.field_errors()["spouse"][0].code, "InvalidCentury");
+//! ```
+use std::borrow::Cow;
+
+use validator::ValidationError;
+
+/// Check whether the given string is a valid kennitala, as with `validate`,
+/// for use as a custom validator with `#[validate(custom(function =
+/// "kennitolur::validate_kennitala"))]`. On failure, the code of the
+/// `ValidationError` is the name of the `KennitalaError` variant, such as
+/// `"InvalidChecksum"`, its message is that of the `KennitalaError`, and the
+/// given string is added as the `value` parameter. Optional fields are only
+/// validated when they are `Some`.
+pub fn validate_kennitala(kennitala: &str) -> Result<(), ValidationError> {
+    crate::validate(kennitala).map_err(|e| {
+        let mut error =
+            ValidationError::new(e.variant_name()).with_message(Cow::Owned(e.to_string()));
+        error.add_param(Cow::Borrowed("value"), &kennitala);
+        error
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use validator::{Validate, ValidationErrors};
+
+    #[derive(Validate)]
+    struct NewPerson {
+        #[validate(length(min = 1))]
+        name: String,
+        #[validate(custom(function = "validate_kennitala"))]
+        kennitala: String,
+        #[validate(custom(function = "validate_kennitala"))]
+        spouse: Option<String>,
+    }
+
+    fn validate(kennitala: &str, spouse: Option<&str>) -> Result<(), ValidationErrors> {
+        NewPerson {
+            name: "Jón".to_string(),
+            kennitala: kennitala.to_string(),
+            spouse: spouse.map(str::to_string),
+        }
+        .validate()
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(validate("3110002920", None), Ok(()));
+        assert_eq!(validate("3110002920", Some("1703715939")), Ok(()));
+    }
+
+    #[test]
+    fn validation_error() {
+        let error = validate_kennitala("3110002930").unwrap_err();
+        assert_eq!(error.code, "InvalidChecksum");
+        assert_eq!(
+            error.message.as_deref(),
+            Some("The kennitala's checksum is invalid")
+        );
+        assert_eq!(error.params["value"], json!("3110002930"));
+    }
+
+    #[test]
+    fn aggregated_errors() {
+        let person = NewPerson {
+            name: String::new(),
+            kennitala: "31100O2920".to_string(),
+            spouse: Some("311000".to_string()),
+        };
+        let errors = person.validate().unwrap_err();
+        let fields = errors.field_errors();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields["name"][0].code, "length");
+        assert_eq!(fields["kennitala"][0].code, "InvalidNumber");
+        assert_eq!(fields["kennitala"][0].params["value"], json!("31100O2920"));
+        assert_eq!(fields["spouse"][0].code, "InvalidLength");
+        assert_eq!(
+            fields["spouse"][0].message.as_deref(),
+            Some("Length 6 is invalid")
+        );
+        assert_eq!(fields["spouse"][0].params["value"], json!("311000"));
+    }
+
+    #[test]
+    fn optional_field() {
+        let errors = validate("3110002920", Some("3110002921")).unwrap_err();
+        let fields = errors.field_errors();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields["spouse"][0].code, "InvalidCentury");
+    }
+}
@@ -0,0 +1,125 @@
+//! Validation of kennitölur from JavaScript through `wasm-bindgen`, for
+//! example for client-side form validation, with the same rules as on the
+//! backend. Build with `default-features = false` to leave out `chrono`, which
+//! none of this needs.
+//!
+//! ```js
+//! import { Kennitala, validate_kennitala } from "kennitolur";
+//!
+//! try {
+//!     const kennitala = new Kennitala("3110002920");
+//!     console.log(kennitala.toString(), kennitala.getBirthYear());
+//! } catch (error) {
+//!     console.log(error.name); // Such as "InvalidChecksum"
+//! }
+//! console.log(Kennitala.isValid("3110002930")); // false
+//!
+//! try {
+//!     const kennitala = validate_kennitala("3110002920");
+//!     console.log(kennitala.year, kennitala.isCompany);
+//! } catch (message) {
+//!     console.log(message); // Such as "The kennitala's checksum is invalid"
+//! }
+//! ```
+// The code generated by `wasm-bindgen` for exported functions is unsafe.
+#![allow(unsafe_code)]
+
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::{Kennitala, KennitalaError};
+
+/// A kennitala, exported to JavaScript as the class `Kennitala`.
+#[wasm_bindgen(js_name = Kennitala)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct JsKennitala(Kennitala);
+
+#[wasm_bindgen(js_class = Kennitala)]
+impl JsKennitala {
+    /// Create a new kennitala, as with `Kennitala::new`. On failure, throws an
+    /// `Error` whose name is the name of the `KennitalaError` variant, such as
+    /// `"InvalidChecksum"`, and whose message is that of the error.
+    #[wasm_bindgen(constructor)]
+    pub fn new(kennitala: &str) -> Result<JsKennitala, JsValue> {
+        Kennitala::new(kennitala)
+            .map(JsKennitala)
+            .map_err(to_js_error)
+    }
+
+    /// Check whether the given string is a valid kennitala, as with
+    /// `is_valid`.
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(kennitala: &str) -> bool {
+        crate::is_valid(kennitala)
+    }
+
+    /// Get the canonical 10 digits of the kennitala.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Get the year of birth, or of registration for companies.
+    #[wasm_bindgen(js_name = getBirthYear)]
+    pub fn get_birth_year(&self) -> u32 {
+        self.0.get_year()
+    }
+
+    /// Get the month of birth, or of registration for companies, from 1 to 12.
+    #[wasm_bindgen(js_name = getBirthMonth)]
+    pub fn get_birth_month(&self) -> u32 {
+        self.0.get_month()
+    }
+
+    /// Get the day of birth, or of registration for companies, from 1 to 31.
+    #[wasm_bindgen(js_name = getBirthDay)]
+    pub fn get_birth_day(&self) -> u32 {
+        self.0.get_day()
+    }
+}
+
+impl From<Kennitala> for JsKennitala {
+    fn from(kennitala: Kennitala) -> Self {
+        JsKennitala(kennitala)
+    }
+}
+
+impl From<JsKennitala> for Kennitala {
+    fn from(kennitala: JsKennitala) -> Self {
+        kennitala.0
+    }
+}
+
+fn to_js_error(error: KennitalaError) -> JsValue {
+    let js_error = js_sys::Error::new(&error.to_string());
+    js_error.set_name(error.variant_name());
+    js_error.into()
+}
+
+/// Validate the given kennitala, as with `Kennitala::new`. On success, returns
+/// an object with the fields `kennitala` (the canonical 10 digits), `pretty`
+/// (with a hyphen), `day`, `month`, `year`, `randomDigits`, `checksumDigit`
+/// and `isCompany`. On failure, throws the message of the `KennitalaError` as
+/// a string.
+#[wasm_bindgen]
+pub fn validate_kennitala(s: &str) -> Result<JsValue, JsValue> {
+    let kennitala = Kennitala::new(s).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let object = Object::new();
+    let fields = [
+        ("kennitala", JsValue::from_str(&kennitala.to_string())),
+        ("pretty", JsValue::from_str(&format!("{:#}", kennitala))),
+        ("day", JsValue::from(kennitala.get_day())),
+        ("month", JsValue::from(kennitala.get_month())),
+        ("year", JsValue::from(kennitala.get_year())),
+        ("randomDigits", JsValue::from(kennitala.get_random_pair())),
+        (
+            "checksumDigit",
+            JsValue::from(kennitala.get_checksum_digit()),
+        ),
+        ("isCompany", JsValue::from(kennitala.is_company())),
+    ];
+    for (key, value) in &fields {
+        Reflect::set(&object, &JsValue::from_str(key), value)?;
+    }
+    Ok(object.into())
+}
@@ -0,0 +1,263 @@
+#![cfg(feature = "actix-web")]
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use kennitolur::actix_web::KennitalaResponseError;
+use kennitolur::Kennitala;
+use serde_derive::Deserialize;
+use serde_json::{json, Value};
+
+#[derive(Deserialize)]
+struct Search {
+    kennitala: Kennitala,
+}
+
+#[derive(Deserialize)]
+struct NewPerson {
+    name: String,
+    kennitala: Kennitala,
+}
+
+async fn person(kennitala: Kennitala) -> String {
+    format!("{:#}", kennitala)
+}
+
+async fn person_path(path: web::Path<Kennitala>) -> String {
+    format!("{:#}", path.into_inner())
+}
+
+async fn search(query: web::Query<Search>) -> String {
+    format!("{:#}", query.kennitala)
+}
+
+async fn create(person: web::Json<NewPerson>) -> String {
+    format!("{} {:#}", person.name, person.kennitala)
+}
+
+async fn parse(body: String) -> Result<String, KennitalaResponseError> {
+    let kennitala = Kennitala::new(&body)?;
+    Ok(format!("{:#}", kennitala))
+}
+
+macro_rules! app {
+    () => {
+        test::init_service(
+            App::new()
+                .configure(kennitolur::actix_web::configure)
+                .route("/people/{kennitala}", web::get().to(person))
+                .route("/path/{kennitala}", web::get().to(person_path))
+                .route("/search", web::get().to(search))
+                .route("/people", web::post().to(create))
+                .route("/parse", web::post().to(parse))
+                .route("/pairs/{a}/{b}", web::get().to(person)),
+        )
+        .await
+    };
+}
+
+#[actix_web::test]
+async fn path_extraction() {
+    let app = app!();
+    let request = test::TestRequest::get()
+        .uri("/people/3110002920")
+        .to_request();
+    let body = test::call_and_read_body(&app, request).await;
+    assert_eq!(body, "311000-2920");
+
+    let request = test::TestRequest::get()
+        .uri("/people/3110002930")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(
+        body,
+        json!({
+            "error": "InvalidChecksum",
+            "message": "The kennitala's checksum is invalid",
+        })
+    );
+
+    let request = test::TestRequest::get()
+        .uri("/people/31100O2920")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(
+        body,
+        json!({
+            "error": "InvalidNumber",
+            "message": "Invalid character 'O' at position 5",
+        })
+    );
+
+    let request = test::TestRequest::get()
+        .uri("/pairs/3110002920/1703715939")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn serde_path_extraction() {
+    let app = app!();
+    let request = test::TestRequest::get()
+        .uri("/path/1703715939")
+        .to_request();
+    let body = test::call_and_read_body(&app, request).await;
+    assert_eq!(body, "170371-5939");
+
+    let request = test::TestRequest::get()
+        .uri("/path/1703715949")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(
+        body,
+        json!({
+            "error": "InvalidChecksum",
+            "message": "The kennitala's checksum is invalid",
+        })
+    );
+
+    let request = test::TestRequest::get()
+        .uri("/path/31100O2920")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(
+        body,
+        json!({
+            "error": "InvalidNumber",
+            "message": "Invalid character 'O' at position 5",
+        })
+    );
+
+    // Digits only are deserialized as an integer, which is too short here.
+    let request = test::TestRequest::get().uri("/path/311000").to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn serde_path_extraction_unconfigured() {
+    // Without `configure`, `web::Path` responds as it does to any other
+    // deserialization error.
+    let app =
+        test::init_service(App::new().route("/path/{kennitala}", web::get().to(person_path))).await;
+    let request = test::TestRequest::get()
+        .uri("/path/1703715949")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn query_extraction() {
+    let app = app!();
+    let request = test::TestRequest::get()
+        .uri("/search?kennitala=0311203149")
+        .to_request();
+    let body = test::call_and_read_body(&app, request).await;
+    assert_eq!(body, "031120-3149");
+
+    let request = test::TestRequest::get()
+        .uri("/search?kennitala=0311203159")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(
+        body,
+        json!({
+            "error": "InvalidChecksum",
+            "message": "The kennitala's checksum is invalid",
+        })
+    );
+
+    // Other errors are responded to as usual.
+    let request = test::TestRequest::get().uri("/search").to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(response).await;
+    assert_eq!(body, "Query deserialize error: missing field `kennitala`");
+}
+
+#[actix_web::test]
+async fn json_bodies() {
+    let app = app!();
+    let request = test::TestRequest::post()
+        .uri("/people")
+        .set_json(json!({ "name": "Jón", "kennitala": "7112993009" }))
+        .to_request();
+    let body = test::call_and_read_body(&app, request).await;
+    assert_eq!(body, "Jón 711299-3009");
+
+    let request = test::TestRequest::post()
+        .uri("/people")
+        .set_json(json!({ "name": "Jón", "kennitala": "7112993019" }))
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(
+        body,
+        json!({
+            "error": "InvalidChecksum",
+            "message": "The kennitala's checksum is invalid",
+        })
+    );
+
+    let request = test::TestRequest::post()
+        .uri("/people")
+        .set_json(json!({ "name": "Jón", "kennitala": "7112993O09" }))
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(
+        body,
+        json!({
+            "error": "InvalidNumber",
+            "message": "Invalid character 'O' at position 7",
+        })
+    );
+
+    let request = test::TestRequest::post()
+        .uri("/people")
+        .set_json(json!({ "name": "Jón" }))
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(response).await;
+    assert_eq!(
+        body,
+        "Json deserialize error: missing field `kennitala` at line 1 column 15"
+    );
+}
+
+#[actix_web::test]
+async fn response_error() {
+    let app = app!();
+    let request = test::TestRequest::post()
+        .uri("/parse")
+        .set_payload("3110002920")
+        .to_request();
+    let body = test::call_and_read_body(&app, request).await;
+    assert_eq!(body, "311000-2920");
+
+    let request = test::TestRequest::post()
+        .uri("/parse")
+        .set_payload("311000")
+        .to_request();
+    let response = test::call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(
+        body,
+        json!({ "error": "InvalidLength", "message": "Length 6 is invalid" })
+    );
+}
@@ -5,7 +5,7 @@ use std::string::ToString;
 
 #[test]
 fn month_is_zero_1() {
-    const KENNITALAS: [&'static str; 72] = [
+    const KENNITALAS: [&str; 72] = [
         "2200012020",
         "2200012100",
         "2200012290",
@@ -86,7 +86,7 @@ fn month_is_zero_1() {
 }
 #[test]
 fn month_is_zero_2() {
-    const KENNITALAS: [&'static str; 73] = [
+    const KENNITALAS: [&str; 73] = [
         "0300122089",
         "0300122169",
         "0300122249",
@@ -168,7 +168,7 @@ fn month_is_zero_2() {
 }
 #[test]
 fn day_is_zero_1() {
-    const KENNITALAS: [&'static str; 73] = [
+    const KENNITALAS: [&str; 73] = [
         "0003002099",
         "0003002179",
         "0003002259",
@@ -250,7 +250,7 @@ fn day_is_zero_1() {
 }
 #[test]
 fn day_is_zero_2() {
-    const KENNITALAS: [&'static str; 73] = [
+    const KENNITALAS: [&str; 73] = [
         "0011752030",
         "0011752110",
         "0011752380",
@@ -332,7 +332,7 @@ fn day_is_zero_2() {
 }
 #[test]
 fn leap_day_in_2001() {
-    const KENNITALAS: [&'static str; 73] = [
+    const KENNITALAS: [&str; 73] = [
         "2902012090",
         "2902012170",
         "2902012250",
@@ -414,7 +414,7 @@ fn leap_day_in_2001() {
 }
 #[test]
 fn new_years_1999() {
-    const KENNITALAS: [&'static str; 73] = [
+    const KENNITALAS: [&str; 73] = [
         "3112992049",
         "3112992129",
         "3112992209",
@@ -497,7 +497,7 @@ fn new_years_1999() {
 }
 #[test]
 fn new_years_2000() {
-    const KENNITALAS: [&'static str; 73] = [
+    const KENNITALAS: [&str; 73] = [
         "3112002080",
         "3112002160",
         "3112002240",
@@ -580,7 +580,7 @@ fn new_years_2000() {
 }
 #[test]
 fn first_january_2001() {
-    const KENNITALAS: [&'static str; 73] = [
+    const KENNITALAS: [&str; 73] = [
         "0101012040",
         "0101012120",
         "0101012200",
@@ -663,7 +663,7 @@ fn first_january_2001() {
 }
 #[test]
 fn leap_day_in_2000() {
-    const KENNITALAS: [&'static str; 72] = [
+    const KENNITALAS: [&str; 72] = [
         "2902002020",
         "2902002100",
         "2902002290",
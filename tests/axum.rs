@@ -0,0 +1,150 @@
+#![cfg(feature = "axum")]
+
+use axum::body::{to_bytes, Body};
+use axum::extract::{Path, Query};
+use axum::http::{header, Request, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use futures::executor::block_on;
+use kennitolur::Kennitala;
+use serde_derive::Deserialize;
+use tower::ServiceExt;
+
+#[derive(Deserialize)]
+struct Search {
+    kennitala: Kennitala,
+}
+
+#[derive(Deserialize)]
+struct NewPerson {
+    name: String,
+    kennitala: Kennitala,
+}
+
+async fn person(kennitala: Kennitala) -> String {
+    format!("{:#}", kennitala)
+}
+
+async fn person_path(Path(kennitala): Path<Kennitala>) -> String {
+    format!("{:#}", kennitala)
+}
+
+async fn search(Query(search): Query<Search>) -> String {
+    format!("{:#}", search.kennitala)
+}
+
+async fn create(Json(person): Json<NewPerson>) -> String {
+    format!("{} {:#}", person.name, person.kennitala)
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/people/{kennitala}", get(person))
+        .route("/path/{kennitala}", get(person_path))
+        .route("/search", get(search))
+        .route("/people", post(create))
+        .route("/pairs/{a}/{b}", get(person))
+}
+
+fn request(request: Request<Body>) -> (StatusCode, String) {
+    block_on(async {
+        let response = app().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    })
+}
+
+fn get_uri(uri: &str) -> (StatusCode, String) {
+    request(Request::get(uri).body(Body::empty()).unwrap())
+}
+
+#[test]
+fn path_valid() {
+    assert_eq!(
+        get_uri("/people/3110002920"),
+        (StatusCode::OK, "311000-2920".to_string())
+    );
+}
+
+#[test]
+fn path_malformed() {
+    assert_eq!(
+        get_uri("/people/31100O2920"),
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Invalid character 'O' at position 5".to_string()
+        )
+    );
+    assert_eq!(
+        get_uri("/people/311000"),
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Length 6 is invalid".to_string()
+        )
+    );
+}
+
+#[test]
+fn path_wrong_checksum() {
+    assert_eq!(
+        get_uri("/people/3110002930"),
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "The kennitala's checksum is invalid".to_string()
+        )
+    );
+}
+
+#[test]
+fn path_with_several_parameters() {
+    let (status, _) = get_uri("/pairs/3110002920/1703715939");
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn serde_path() {
+    assert_eq!(
+        get_uri("/path/3110002920"),
+        (StatusCode::OK, "311000-2920".to_string())
+    );
+    // Rejected with 400, as any other path that can't be deserialized, but
+    // with the same message as when extracting `Kennitala` directly.
+    for uri in &["/path/3110002930", "/path/31100O2920", "/path/311000"] {
+        let (status, body) = get_uri(uri);
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+        let direct = get_uri(&uri.replace("/path/", "/people/"));
+        assert_eq!(direct.0, StatusCode::UNPROCESSABLE_ENTITY, "{}", uri);
+        assert!(body.contains(&direct.1), "{}: {}", uri, body);
+    }
+}
+
+#[test]
+fn query() {
+    assert_eq!(
+        get_uri("/search?kennitala=1703715939"),
+        (StatusCode::OK, "170371-5939".to_string())
+    );
+    let (status, body) = get_uri("/search?kennitala=1703715949");
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.contains("checksum is invalid"), "{}", body);
+}
+
+#[test]
+fn json_body() {
+    let post = |body: &'static str| {
+        request(
+            Request::post("/people")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+    };
+    assert_eq!(
+        post(r#"{"name": "Jón", "kennitala": "0311203149"}"#),
+        (StatusCode::OK, "Jón 031120-3149".to_string())
+    );
+    let (status, body) = post(r#"{"name": "Jón", "kennitala": "0311203159"}"#);
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(body.contains("checksum is invalid"), "{}", body);
+}
@@ -0,0 +1,9 @@
+// The expected compiler output names `std` paths, and the public items in the
+// crate, both of which differ without the standard library.
+#![cfg(feature = "std")]
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
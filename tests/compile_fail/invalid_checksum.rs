@@ -0,0 +1,7 @@
+use kennitolur::{kt, Kennitala};
+
+const KENNITALA: Kennitala = kt!("3110002910");
+
+fn main() {
+    println!("{}", KENNITALA);
+}
@@ -0,0 +1,5 @@
+use kennitolur::kt;
+
+fn main() {
+    println!("{}", kt!("311000292"));
+}
@@ -0,0 +1,44 @@
+//! Builds the crate as a static library, links the C program in
+//! `tests/ffi/test.c` against it with the header in `include/`, and runs it.
+//! The C compiler is taken from the `CC` environment variable, or is `cc`.
+#![cfg(all(feature = "ffi", unix))]
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+fn run(command: &mut Command) {
+    let status = command
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run {:?}: {}", command, e));
+    assert!(status.success(), "{:?} failed with {}", command, status);
+}
+
+#[test]
+fn c_harness() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // A separate target directory, so that the build doesn't wait on the lock
+    // held by the `cargo test` running this.
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    run(Command::new(cargo)
+        .current_dir(manifest_dir)
+        .args(["rustc", "--quiet", "--lib", "--no-default-features"])
+        .args(["--features", "ffi", "--crate-type", "staticlib"])
+        .arg("--target-dir")
+        .arg(&target_dir));
+
+    let library = target_dir.join("debug").join("libkennitolur.a");
+    let executable = target_dir.join("ffi_test");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    run(Command::new(cc)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(manifest_dir.join("include"))
+        .arg(manifest_dir.join("tests").join("ffi").join("test.c"))
+        .arg(&library)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&executable));
+
+    run(&mut Command::new(&executable));
+}
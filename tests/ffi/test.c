@@ -0,0 +1,77 @@
+/* Exercises the C interface through the generated header. Built and run by
+ * tests/ffi.rs. */
+#include <stdio.h>
+#include <string.h>
+
+#include "kennitolur.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                      \
+    do {                                                                      \
+        if (!(condition)) {                                                   \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,  \
+                    #condition);                                              \
+            failures++;                                                       \
+        }                                                                     \
+    } while (0)
+
+static int validate(const char *kennitala) {
+    return kennitolur_validate(kennitala, strlen(kennitala));
+}
+
+static void test_validate(void) {
+    CHECK(validate("3110002920") == KENNITOLUR_OK);
+    CHECK(validate("7112993009") == KENNITOLUR_OK);
+    CHECK(validate("311000") == KENNITOLUR_INVALID_LENGTH);
+    CHECK(validate("31100O2920") == KENNITOLUR_INVALID_NUMBER);
+    CHECK(validate("3210002070") == KENNITOLUR_INVALID_DAY);
+    CHECK(validate("3113002020") == KENNITOLUR_INVALID_MONTH);
+    CHECK(validate("3110001010") == KENNITOLUR_INVALID_RANDOM_DIGITS);
+    CHECK(validate("3110002930") == KENNITOLUR_INVALID_CHECKSUM);
+    CHECK(validate("3110002921") == KENNITOLUR_INVALID_CENTURY);
+    CHECK(validate("1111111111") == KENNITOLUR_PLACEHOLDER);
+    /* The string need not be null-terminated. */
+    CHECK(kennitolur_validate("3110002920 and more", 10) == KENNITOLUR_OK);
+    CHECK(kennitolur_validate(NULL, 10) == KENNITOLUR_NULL_POINTER);
+}
+
+static void test_parse_and_format(void) {
+    uint32_t packed = 0;
+    char formatted[11];
+
+    CHECK(kennitolur_parse("0311203149", 10, &packed) == KENNITOLUR_OK);
+    CHECK(kennitolur_format(packed, formatted) == KENNITOLUR_OK);
+    CHECK(strcmp(formatted, "0311203149") == 0);
+
+    CHECK(kennitolur_parse("3110002920", 10, &packed) == KENNITOLUR_OK);
+    CHECK(packed == 0x0524015f);
+    CHECK(kennitolur_format(packed, formatted) == KENNITOLUR_OK);
+    CHECK(strcmp(formatted, "3110002920") == 0);
+}
+
+static void test_failures(void) {
+    uint32_t packed = 42;
+    char formatted[11] = "unchanged";
+
+    CHECK(kennitolur_parse("1703715949", 10, &packed) ==
+          KENNITOLUR_INVALID_CHECKSUM);
+    CHECK(packed == 42);
+    CHECK(kennitolur_parse("1703715939", 10, NULL) == KENNITOLUR_NULL_POINTER);
+
+    CHECK(kennitolur_format(0x4524015f, formatted) ==
+          KENNITOLUR_INVALID_REPRESENTATION);
+    CHECK(strcmp(formatted, "unchanged") == 0);
+    CHECK(kennitolur_format(0x0524015f, NULL) == KENNITOLUR_NULL_POINTER);
+}
+
+int main(void) {
+    test_validate();
+    test_parse_and_format();
+    test_failures();
+    if (failures > 0) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    return 0;
+}
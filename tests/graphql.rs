@@ -0,0 +1,84 @@
+#![cfg(feature = "async-graphql")]
+
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+use futures::executor::block_on;
+use kennitolur::Kennitala;
+
+struct Query;
+
+#[Object]
+impl Query {
+    /// Return the given kennitala after validating it.
+    async fn echo(&self, kennitala: Kennitala) -> Kennitala {
+        kennitala
+    }
+
+    async fn is_company(&self, kennitala: Kennitala) -> bool {
+        kennitala.is_company()
+    }
+}
+
+fn execute(query: &str) -> async_graphql::Response {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    block_on(schema.execute(query))
+}
+
+#[test]
+fn string_round_trip() {
+    let response = execute(r#"{ echo(kennitala: "3110002920") }"#);
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data, serde_json::json!({"echo": "3110002920"}));
+}
+
+#[test]
+fn integer_input() {
+    let response = execute(r#"{ echo(kennitala: 311203149) isCompany(kennitala: 7112993009) }"#);
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(
+        data,
+        serde_json::json!({"echo": "0311203149", "isCompany": true})
+    );
+}
+
+#[test]
+fn invalid_kennitala() {
+    let response = execute(r#"{ echo(kennitala: "3110002921") }"#);
+    assert_eq!(response.errors.len(), 1);
+    let message = &response.errors[0].message;
+    assert!(
+        message.contains("Century of birth is invalid"),
+        "{}",
+        message
+    );
+
+    let response = execute(r#"{ echo(kennitala: -3110002920) }"#);
+    assert_eq!(response.errors.len(), 1);
+    let message = &response.errors[0].message;
+    assert!(
+        message.contains("Kennitala can not be negative, found -3110002920"),
+        "{}",
+        message
+    );
+
+    let response = execute(r#"{ echo(kennitala: 3110002920.5) }"#);
+    assert_eq!(response.errors.len(), 1);
+    let message = &response.errors[0].message;
+    assert!(message.contains("must be an integer"), "{}", message);
+
+    let response = execute(r#"{ echo(kennitala: true) }"#);
+    assert_eq!(response.errors.len(), 1);
+}
+
+#[test]
+fn schema_definition() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let sdl = schema.sdl();
+    assert!(sdl.contains("scalar Kennitala"), "{}", sdl);
+    assert!(
+        sdl.contains("echo(kennitala: Kennitala!): Kennitala!"),
+        "{}",
+        sdl
+    );
+}
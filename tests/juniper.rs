@@ -0,0 +1,138 @@
+#![cfg(feature = "juniper")]
+
+use juniper::{
+    execute_sync, graphql_object, graphql_value, EmptyMutation, EmptySubscription, GraphQLError,
+    InputValue, RootNode, Variables,
+};
+use kennitolur::Kennitala;
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    /// Return the given kennitala after validating it.
+    fn echo(kennitala: Kennitala) -> Kennitala {
+        kennitala
+    }
+
+    fn is_company(kennitala: Kennitala) -> bool {
+        kennitala.is_company()
+    }
+}
+
+type Schema = RootNode<'static, Query, EmptyMutation, EmptySubscription>;
+
+fn schema() -> Schema {
+    Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[test]
+fn literal_round_trip() {
+    let (value, errors) = execute_sync(
+        r#"{ echo(kennitala: "3110002920") isCompany(kennitala: "7112993009") }"#,
+        None,
+        &schema(),
+        &Variables::new(),
+        &(),
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(
+        value,
+        graphql_value!({"echo": "3110002920", "isCompany": true})
+    );
+}
+
+#[test]
+fn variable_round_trip() {
+    let mut variables = Variables::new();
+    variables.insert("kt".into(), InputValue::scalar("0311203149"));
+    let (value, errors) = execute_sync(
+        "query($kt: Kennitala!) { echo(kennitala: $kt) }",
+        None,
+        &schema(),
+        &variables,
+        &(),
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(value, graphql_value!({"echo": "0311203149"}));
+}
+
+#[test]
+fn invalid_literal() {
+    let result = execute_sync(
+        r#"{ echo(kennitala: "3110002921") }"#,
+        None,
+        &schema(),
+        &Variables::new(),
+        &(),
+    );
+    match result {
+        Err(GraphQLError::ValidationError(errors)) => {
+            assert_eq!(errors.len(), 1);
+            // juniper leaves out the reason given by the scalar for literals,
+            // unlike for variables.
+            assert_eq!(
+                errors[0].message(),
+                r#"Invalid value for argument "kennitala", reason: Invalid value ""3110002921"" for type "Kennitala""#
+            );
+        }
+        result => panic!("expected a validation error, got {:?}", result),
+    }
+}
+
+#[test]
+fn invalid_variable() {
+    let mut variables = Variables::new();
+    variables.insert("kt".into(), InputValue::scalar("3110002921"));
+    let result = execute_sync(
+        "query($kt: Kennitala!) { echo(kennitala: $kt) }",
+        None,
+        &schema(),
+        &variables,
+        &(),
+    );
+    match result {
+        Err(GraphQLError::ValidationError(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(
+                errors[0].message(),
+                "Variable \"$kt\" got invalid value. Expected input scalar `Kennitala`. \
+                 Got: `\"3110002921\"`. Details: Century of birth is invalid."
+            );
+        }
+        result => panic!("expected a validation error, got {:?}", result),
+    }
+
+    let mut variables = Variables::new();
+    variables.insert("kt".into(), InputValue::scalar(3110002));
+    let result = execute_sync(
+        "query($kt: Kennitala!) { echo(kennitala: $kt) }",
+        None,
+        &schema(),
+        &variables,
+        &(),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn introspection() {
+    let (value, errors) = execute_sync(
+        r#"{ __type(name: "Kennitala") { kind description } }"#,
+        None,
+        &schema(),
+        &Variables::new(),
+        &(),
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(
+        value,
+        graphql_value!({"__type": {
+            "kind": "SCALAR",
+            "description": "A kennitala, as its canonical 10 digit string.",
+        }})
+    );
+}
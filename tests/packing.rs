@@ -0,0 +1,145 @@
+//! Property tests checking that every field of a kennitala survives being
+//! packed into its internal representation.
+#![cfg(not(target_arch = "wasm32"))]
+use kennitolur::{days_in_month, Kennitala};
+use proptest::prelude::*;
+
+/// The fields of a valid kennitala.
+#[derive(Debug, Clone)]
+struct Fields {
+    day: u32,
+    month: u32,
+    short_year: u32,
+    century: u32,
+    randoms: u32,
+    company: bool,
+}
+
+impl Fields {
+    fn year(&self) -> u32 {
+        year(self.century, self.short_year)
+    }
+
+    fn checksum(&self) -> u32 {
+        let day = self.day + if self.company { 40 } else { 0 };
+        let digits = [
+            day / 10,
+            day % 10,
+            self.month / 10,
+            self.month % 10,
+            self.short_year / 10,
+            self.short_year % 10,
+            self.randoms / 10,
+            self.randoms % 10,
+        ];
+        let sum: u32 = digits
+            .iter()
+            .zip(&[3, 2, 7, 6, 5, 4, 3, 2])
+            .map(|(d, w)| d * w)
+            .sum();
+        match sum % 11 {
+            0 => 0,
+            r => 11 - r,
+        }
+    }
+
+    fn to_kennitala_string(&self) -> String {
+        format!(
+            "{:02}{:02}{:02}{:02}{}{}",
+            self.day + if self.company { 40 } else { 0 },
+            self.month,
+            self.short_year,
+            self.randoms,
+            self.checksum(),
+            self.century
+        )
+    }
+}
+
+fn year(century: u32, short_year: u32) -> u32 {
+    short_year
+        + match century {
+            8 => 1800,
+            9 => 1900,
+            _ => 2000,
+        }
+}
+
+/// Generate the fields of valid kennitölur. The day is drawn after the month
+/// and year are known, and every field shrinks towards its smallest value.
+fn fields() -> impl Strategy<Value = Fields> {
+    (
+        prop_oneof![Just(9u32), Just(0), Just(8)],
+        0u32..100,
+        1u32..=12,
+        20u32..100,
+        any::<bool>(),
+    )
+        .prop_flat_map(|(century, short_year, month, randoms, company)| {
+            let days = days_in_month(month, year(century, short_year)).unwrap();
+            (1..=u32::from(days)).prop_map(move |day| Fields {
+                day,
+                month,
+                short_year,
+                century,
+                randoms,
+                company,
+            })
+        })
+        // A checksum of 10 can't be represented.
+        .prop_filter("checksum is 10", |fields| fields.checksum() < 10)
+}
+
+proptest! {
+    #[test]
+    fn getters_return_the_fields(fields in fields()) {
+        let string = fields.to_kennitala_string();
+        let kt = Kennitala::new(&string).unwrap();
+        prop_assert_eq!(kt.get_day(), fields.day);
+        prop_assert_eq!(kt.get_month(), fields.month);
+        prop_assert_eq!(kt.get_short_year(), fields.short_year);
+        prop_assert_eq!(kt.get_year(), fields.year());
+        prop_assert_eq!(kt.get_short_century(), fields.century);
+        prop_assert_eq!(u32::from(kt.get_random_pair()), fields.randoms);
+        prop_assert_eq!(u32::from(kt.get_checksum_digit()), fields.checksum());
+        prop_assert_eq!(kt.get_randoms(), fields.randoms * 10 + fields.checksum());
+        prop_assert_eq!(kt.is_company(), fields.company);
+        prop_assert!(kt.has_valid_checksum());
+    }
+
+    #[test]
+    fn representations_round_trip(fields in fields()) {
+        let string = fields.to_kennitala_string();
+        let kt = Kennitala::new(&string).unwrap();
+        prop_assert_eq!(kt.to_string(), string.clone());
+        let fixed = kt.to_fixed();
+        prop_assert_eq!(fixed.as_str(), string.as_str());
+        let digits: Vec<u8> = string.bytes().map(|b| b - b'0').collect();
+        prop_assert_eq!(&kt.digits()[..], &digits[..]);
+        prop_assert_eq!(Kennitala::from_packed(kt.as_u32()), Some(kt));
+        prop_assert_eq!(Kennitala::from_u64(kt.to_decimal_u64()), Ok(kt));
+        prop_assert_eq!(kt.to_decimal_u64(), string.parse::<u64>().unwrap());
+    }
+
+    #[test]
+    fn from_raw_accepts_only_valid_kennitolur(raw in any::<u32>()) {
+        if let Ok(kt) = Kennitala::from_raw(raw) {
+            prop_assert_eq!(kt.to_raw(), raw);
+            let string = kt.to_string();
+            let reparsed = if kt.has_valid_checksum() {
+                Kennitala::new(&string)
+            } else {
+                Kennitala::new_unchecked_checksum(&string)
+            };
+            prop_assert_eq!(reparsed, Ok(kt));
+        }
+        prop_assert_eq!(Kennitala::from_be_bytes(raw.to_be_bytes()), Kennitala::from_raw(raw));
+    }
+
+    #[test]
+    fn from_raw_round_trips(fields in fields()) {
+        let kt = Kennitala::new(&fields.to_kennitala_string()).unwrap();
+        prop_assert_eq!(Kennitala::from_raw(kt.to_raw()), Ok(kt));
+        prop_assert_eq!(Kennitala::from_be_bytes(kt.to_be_bytes()), Ok(kt));
+    }
+}
@@ -0,0 +1,58 @@
+//! These tests need a PostgreSQL database, given by the `DATABASE_URL`
+//! environment variable, and are run with `cargo test --features postgres --
+//! --ignored`.
+#![cfg(feature = "postgres")]
+
+use kennitolur::Kennitala;
+use tokio_postgres::{Client, NoTls};
+
+async fn connect() -> Client {
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let (client, connection) = tokio_postgres::connect(&url, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+#[ignore]
+async fn round_trip() {
+    let client = connect().await;
+    let kt = Kennitala::new("0311203149").unwrap();
+    let row = client
+        .query_one(
+            "SELECT $1::text, $1::varchar, $1::char(10), $1::char(12)",
+            &[&kt],
+        )
+        .await
+        .unwrap();
+    for i in 0..4 {
+        assert_eq!(row.get::<_, Kennitala>(i), kt);
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn null() {
+    let client = connect().await;
+    let row = client
+        .query_one("SELECT $1::char(10)", &[&None::<Kennitala>])
+        .await
+        .unwrap();
+    assert_eq!(row.get::<_, Option<Kennitala>>(0), None);
+}
+
+#[tokio::test]
+#[ignore]
+async fn corrupt_row() {
+    let client = connect().await;
+    let row = client
+        .query_one("SELECT '3110002921'::text", &[])
+        .await
+        .unwrap();
+    let e = row.try_get::<_, Kennitala>(0).unwrap_err();
+    assert!(
+        e.to_string().contains("error deserializing column 0"),
+        "{}",
+        e
+    );
+}
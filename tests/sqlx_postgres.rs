@@ -0,0 +1,73 @@
+//! These tests need a PostgreSQL database, given by the `DATABASE_URL`
+//! environment variable, and are run with `cargo test --features sqlx-postgres
+//! -- --ignored`.
+#![cfg(feature = "sqlx-postgres")]
+
+use kennitolur::Kennitala;
+use sqlx::{Connection, PgConnection};
+
+async fn connect() -> PgConnection {
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    PgConnection::connect(&url).await.unwrap()
+}
+
+#[tokio::test]
+#[ignore]
+async fn round_trip() {
+    let mut conn = connect().await;
+    let kt = Kennitala::new("0311203149").unwrap();
+    let (text, decoded): (String, Kennitala) = sqlx::query_as("SELECT $1::text, $1::char(10)")
+        .bind(kt)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(text, "0311203149");
+    assert_eq!(decoded, kt);
+}
+
+#[tokio::test]
+#[ignore]
+async fn padded_char() {
+    let mut conn = connect().await;
+    let kt = Kennitala::new("7112993009").unwrap();
+    let (padded, decoded): (String, Kennitala) =
+        sqlx::query_as("SELECT $1::char(12), $1::char(12)")
+            .bind(kt)
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+    assert_eq!(padded, "7112993009  ");
+    assert_eq!(decoded, kt);
+}
+
+#[tokio::test]
+#[ignore]
+async fn null() {
+    let mut conn = connect().await;
+    let (decoded,): (Option<Kennitala>,) = sqlx::query_as("SELECT $1::char(10)")
+        .bind(None::<Kennitala>)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(decoded, None);
+    let (decoded,): (Option<Kennitala>,) = sqlx::query_as("SELECT '7112993009'::varchar")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(decoded, Some(Kennitala::new("7112993009").unwrap()));
+}
+
+#[tokio::test]
+#[ignore]
+async fn corrupt_row() {
+    let mut conn = connect().await;
+    let result: Result<(Kennitala,), _> = sqlx::query_as("SELECT '3110002921'::text")
+        .fetch_one(&mut conn)
+        .await;
+    match result {
+        Err(sqlx::Error::ColumnDecode { source, .. }) => {
+            assert_eq!(source.to_string(), "Century of birth is invalid");
+        }
+        result => panic!("expected a decode error, got {:?}", result),
+    }
+}
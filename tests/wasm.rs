@@ -0,0 +1,71 @@
+//! Run with `wasm-pack test --node -- --features wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use js_sys::Reflect;
+use kennitolur::wasm::{validate_kennitala, JsKennitala};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn get(object: &JsValue, key: &str) -> JsValue {
+    Reflect::get(object, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn valid() {
+    let kennitala = validate_kennitala("3110002920").unwrap();
+    assert_eq!(get(&kennitala, "kennitala"), "3110002920");
+    assert_eq!(get(&kennitala, "pretty"), "311000-2920");
+    assert_eq!(get(&kennitala, "day"), 31);
+    assert_eq!(get(&kennitala, "month"), 10);
+    assert_eq!(get(&kennitala, "year"), 2000);
+    assert_eq!(get(&kennitala, "randomDigits"), 29);
+    assert_eq!(get(&kennitala, "checksumDigit"), 2);
+    assert_eq!(get(&kennitala, "isCompany"), false);
+}
+
+#[wasm_bindgen_test]
+fn invalid() {
+    let error = validate_kennitala("3110002930").unwrap_err();
+    assert_eq!(error, "The kennitala's checksum is invalid");
+    let error = validate_kennitala("311000").unwrap_err();
+    assert_eq!(error, "Length 6 is invalid");
+}
+
+#[wasm_bindgen_test]
+fn class() {
+    let kennitala = JsKennitala::new("3110002920").unwrap();
+    assert_eq!(kennitala.to_js_string(), "3110002920");
+    assert_eq!(kennitala.get_birth_year(), 2000);
+    assert_eq!(kennitala.get_birth_month(), 10);
+    assert_eq!(kennitala.get_birth_day(), 31);
+
+    let company = JsKennitala::new("7112993009").unwrap();
+    assert_eq!(company.get_birth_year(), 1999);
+    assert_eq!(company.get_birth_month(), 12);
+    assert_eq!(company.get_birth_day(), 31);
+
+    assert!(JsKennitala::is_valid("0311203149"));
+    assert!(!JsKennitala::is_valid("0311203159"));
+}
+
+#[wasm_bindgen_test]
+fn class_errors() {
+    let cases = [
+        (
+            "3110002930",
+            "InvalidChecksum",
+            "The kennitala's checksum is invalid",
+        ),
+        ("311000", "InvalidLength", "Length 6 is invalid"),
+        (
+            "3110002921",
+            "InvalidCentury",
+            "Century of birth is invalid",
+        ),
+    ];
+    for (input, name, message) in &cases {
+        let error: js_sys::Error = JsKennitala::new(input).unwrap_err().dyn_into().unwrap();
+        assert_eq!(error.name(), *name);
+        assert_eq!(error.message(), *message);
+    }
+}